@@ -0,0 +1,21 @@
+// Generate the C header for the `ffi` feature.
+// The header is written to `OUT_DIR`; `src/ffi_test.rs` checks that
+// the copy in `include/pixel_param.h` is up to date.
+
+fn main() {
+    #[cfg(feature = "ffi")]
+    {
+        let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let out_dir = std::env::var("OUT_DIR").unwrap();
+        println!("cargo:rerun-if-changed=src/ffi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+        let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir))
+            .expect("Error reading cbindgen.toml");
+        cbindgen::Builder::new()
+            .with_crate(&crate_dir)
+            .with_config(config)
+            .generate()
+            .expect("Error generating the C header")
+            .write_to_file(format!("{}/pixel_param.h", out_dir));
+    }
+}
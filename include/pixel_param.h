@@ -0,0 +1,148 @@
+#ifndef PIXEL_PARAM_H
+#define PIXEL_PARAM_H
+
+/* Warning, this file is autogenerated by cbindgen. Do not modify this manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The call succeeded.
+ */
+#define PIXEL_PARAM_OK 0
+
+/**
+ * A required pointer argument is null.
+ */
+#define PIXEL_PARAM_ERR_NULL_POINTER -1
+
+/**
+ * The seed is shorter than 32 bytes.
+ */
+#define PIXEL_PARAM_ERR_SEED_TOO_SHORT -2
+
+/**
+ * The ciphersuite id is not supported.
+ */
+#define PIXEL_PARAM_ERR_CIPHERSUITE -3
+
+/**
+ * The output buffer is too small; the required length is written to `out_len`.
+ */
+#define PIXEL_PARAM_ERR_BUFFER_TOO_SMALL -4
+
+/**
+ * The blob is not a valid public parameter.
+ */
+#define PIXEL_PARAM_ERR_DESERIALIZE -5
+
+/**
+ * An unexpected internal error occurred.
+ */
+#define PIXEL_PARAM_ERR_INTERNAL -6
+
+/**
+ * The public parameter consists of the following ...
+ * * g2: group generators for `PixelG2` group
+ * * h: a `PixelG1` element,
+ * * hlist: D+1 PixelG1 elements `h_0, h_1, ..., h_d`
+ *
+ * The groups are given by the pairing engine `E`; see `PubParam` for BLS12-381.
+ */
+typedef struct GenericPubParam_Bls12 GenericPubParam_Bls12;
+
+/**
+ * The public parameter over BLS12-381, with the groups
+ * `PixelG1` and `PixelG2` given by feature `no_swap`.
+ */
+typedef struct GenericPubParam_Bls12 PubParam;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Writes the default public parameter into `out`.
+ * The parameter must be released with `pixel_param_free`.
+ *
+ * # Safety
+ * `out` must be a valid pointer.
+ */
+int32_t pixel_param_default(PubParam **out);
+
+/**
+ * Generates a public parameter from `seed_len` bytes of `seed` and
+ * a ciphersuite id, and writes it into `out`.
+ * The parameter must be released with `pixel_param_free`.
+ *
+ * # Safety
+ * `seed` must point to `seed_len` readable bytes and `out` must be a valid pointer.
+ */
+int32_t pixel_param_init(const uint8_t *seed, uintptr_t seed_len, uint8_t csid, PubParam **out);
+
+/**
+ * Serializes the public parameter into `out`, which has `*out_len` bytes.
+ * On success, `*out_len` is set to the number of bytes written.
+ * If `out` is null or too small, `*out_len` is set to the required
+ * length and `PIXEL_PARAM_ERR_BUFFER_TOO_SMALL` is returned.
+ *
+ * # Safety
+ * `pp` must be a parameter obtained from this library, `out_len` a valid
+ * pointer, and `out` either null or pointing to `*out_len` writable bytes.
+ */
+int32_t pixel_param_serialize(const PubParam *pp,
+                              bool compressed,
+                              uint8_t *out,
+                              uintptr_t *out_len);
+
+/**
+ * Deserializes `len` bytes of `buf` into a public parameter,
+ * and writes it into `out`.
+ * The parameter must be released with `pixel_param_free`.
+ *
+ * # Safety
+ * `buf` must point to `len` readable bytes and `out` must be a valid pointer.
+ */
+int32_t pixel_param_deserialize(const uint8_t *buf, uintptr_t len, bool compressed, PubParam **out);
+
+/**
+ * Returns the depth of the public parameter, or 0 if `pp` is null.
+ *
+ * # Safety
+ * `pp` must be null or a parameter obtained from this library.
+ */
+uintptr_t pixel_param_depth(const PubParam *pp);
+
+/**
+ * Returns the ciphersuite id of the public parameter, or 0xFF if `pp` is null.
+ *
+ * # Safety
+ * `pp` must be null or a parameter obtained from this library.
+ */
+uint8_t pixel_param_ciphersuite(const PubParam *pp);
+
+/**
+ * Writes the 32 bytes fingerprint of the public parameter into `out`.
+ *
+ * # Safety
+ * `pp` must be a parameter obtained from this library and
+ * `out` must point to 32 writable bytes.
+ */
+int32_t pixel_param_fingerprint(const PubParam *pp, uint8_t *out);
+
+/**
+ * Releases a public parameter. Passing null is a no-op.
+ *
+ * # Safety
+ * `pp` must be null or a parameter obtained from this library,
+ * and must not be used afterwards.
+ */
+void pixel_param_free(PubParam *pp);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* PIXEL_PARAM_H */
@@ -0,0 +1,36 @@
+# cross check the rust implementation, via the `pixel_param` python module,
+# against the python reference in `param.py` and the known answer test `kat.txt`.
+#
+# build and install the module first with `maturin develop` from this folder.
+
+import os
+import pixel_param
+from param import gen_param, seed
+
+# the default parameter matches the KAT
+pp = pixel_param.PubParam.default()
+with open("kat.txt", "rb") as f:
+    kat = f.read()
+assert pp.to_bytes(False) == kat
+assert pixel_param.PubParam.from_bytes(kat, False) == pp
+assert pixel_param.PubParam.init(seed, 0) == pp
+assert pp.depth() == 32
+assert len(pp.hlist()) == pp.depth() + 1
+
+# random seeds produce the same parameters in rust and in python,
+# for each of the KDFs selected by the ciphersuite ids
+for ciphersuite in range(8):
+    for _ in range(4):
+        s = os.urandom(32)
+        pp = pixel_param.PubParam.init(s, ciphersuite)
+        assert pp.to_bytes(False) == gen_param(s, ciphersuite)
+
+# invalid inputs raise a ValueError
+for args in [(seed[:31], 0), (seed, 0xFF)]:
+    try:
+        pixel_param.PubParam.init(*args)
+        assert False
+    except ValueError:
+        pass
+
+print("rust and python parameters match")
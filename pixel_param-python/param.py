@@ -1,5 +1,8 @@
+import os
 import sys
-sys.path.append("/Users/zhenfei/Documents/GitHub/bls_sigs_ref/python-impl")
+# path to the python implementation of bls_sigs_ref, e.g.
+# `export BLS_SIGS_REF=/path/to/bls_sigs_ref/python-impl`
+sys.path.append(os.environ.get("BLS_SIGS_REF", "../../bls_sigs_ref/python-impl"))
 
 import hkdf
 import hashlib
@@ -46,40 +49,60 @@ seed = bytes([
     0x1f, 0x83, 0xd9, 0xab, 0xfb, 0x41, 0xbd, 0x6b, 0x5b, 0xe0, 0xcd, 0x19, 0x13, 0x7e, 0x21, 0x79,
 ])
 
-ciphersuite = b"\0"
 d = 32
 
-# extract the secret m
-m = hkdf.hkdf_extract(salt=DOM_SEP_PARAM_GEN, input_key_material=seed, hash=hashlib.sha512);
+# the hash function of the HKDF, selected by the ciphersuite id
+KDF_HASH = {
+    0: hashlib.sha512, 1: hashlib.sha512,
+    2: hashlib.sha256, 3: hashlib.sha256,
+    4: hashlib.blake2b, 5: hashlib.blake2b,
+    6: hashlib.sha3_512, 7: hashlib.sha3_512,
+}
 
-# generate h using hash_to_group
-info = bytes("H2G_h", "ascii")
-# expand the secret
-key = hkdf.hkdf_expand(pseudo_random_key=m, info=info, length=32, hash=hashlib.sha512)
-# hash to G2
-h = map2curve_osswu2(key, ciphersuite)
+def gen_param(seed, ciphersuite):
+    """
+    generate the (uncompressed) parameter blob from a seed and a ciphersuite id,
+    following the same steps as `PubParam::init` in rust
+    """
+    kdf_hash = KDF_HASH[ciphersuite]
+    ciphersuite = bytes([ciphersuite])
 
-# generate hlistusing hash_to_group
-hlist =[]
-for i in range(d+1):
-    info  = b"H2G_h" + I2OSP(i,1)
+    # extract the secret m
+    m = hkdf.hkdf_extract(salt=DOM_SEP_PARAM_GEN, input_key_material=seed, hash=kdf_hash);
+
+    # generate h using hash_to_group
+    info = bytes("H2G_h", "ascii")
     # expand the secret
-    key = hkdf.hkdf_expand(pseudo_random_key=m, info=info, length=32, hash=hashlib.sha512)
+    key = hkdf.hkdf_expand(pseudo_random_key=m, info=info, length=32, hash=kdf_hash)
     # hash to G2
-    hi = map2curve_osswu2(key, ciphersuite)
-    hlist.append(hi)
-
-# formulate the outputs
-buf = b"%c" % ciphersuite
-buf = buf + b"%c" % d
-buf = buf + serialize(g1gen, False)
-buf = buf + serialize(h, False)
-for i in range(d+1):
-    buf = buf + serialize(hlist[i], False)
-
-# write to the output
-f = open("kat_python.txt", "wb")
-f.write(buf)
-f.close()
-
-assert filecmp.cmp("kat_python.txt", "kat.txt")
+    h = map2curve_osswu2(key, ciphersuite)
+
+    # generate hlistusing hash_to_group
+    hlist =[]
+    for i in range(d+1):
+        info  = b"H2G_h" + I2OSP(i,1)
+        # expand the secret
+        key = hkdf.hkdf_expand(pseudo_random_key=m, info=info, length=32, hash=kdf_hash)
+        # hash to G2
+        hi = map2curve_osswu2(key, ciphersuite)
+        hlist.append(hi)
+
+    # formulate the outputs
+    buf = ciphersuite
+    buf = buf + b"%c" % d
+    buf = buf + serialize(g1gen, False)
+    buf = buf + serialize(h, False)
+    for i in range(d+1):
+        buf = buf + serialize(hlist[i], False)
+    return buf
+
+
+if __name__ == "__main__":
+    buf = gen_param(seed, 0)
+
+    # write to the output
+    f = open("kat_python.txt", "wb")
+    f.write(buf)
+    f.close()
+
+    assert filecmp.cmp("kat_python.txt", "kat.txt")
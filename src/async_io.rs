@@ -0,0 +1,48 @@
+// This module reads and writes the parameter blob over tokio's
+// asynchronous streams, so that loading the parameters from a socket
+// or a slow disk does not block the executor.
+// The checks and the write sequence are shared with the sync path
+// in `serdes`: the header is checked as soon as the first two bytes
+// arrive, then `from_bytes` decodes the whole blob.
+
+use crate::serdes::{blob_buffer, decode_blob};
+use crate::{GenericPubParam, PixelEngine};
+use std::io::Result;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+impl<E: PixelEngine> GenericPubParam<E> {
+    /// Reads a blob `|ciphersuite id| depth | g2 | h | hlist |` from the reader.
+    /// Returns an error if the reader fails, or under the same conditions
+    /// as `SerDes::deserialize`.
+    pub async fn read_async<R: AsyncRead + Unpin>(
+        reader: &mut R,
+        compressed: bool,
+    ) -> Result<Self> {
+        // the ciphersuite id and the depth
+        let mut header = [0u8; 2];
+        reader.read_exact(&mut header).await?;
+
+        // check them, then read the remaining of the blob, and decode the whole blob
+        let mut buf = blob_buffer::<E>(header, compressed)?;
+        reader.read_exact(&mut buf[2..]).await?;
+        decode_blob(&buf, compressed)
+    }
+
+    /// Writes the blob `|ciphersuite id| depth | g2 | h | hlist |` to the writer,
+    /// and flushes it.
+    /// Returns an error if the writer fails, or under the same conditions
+    /// as `SerDes::serialize`.
+    pub async fn write_async<W: AsyncWrite + Unpin>(
+        &self,
+        writer: &mut W,
+        compressed: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        for part in self.parts_to_write()? {
+            buf.clear();
+            part.append_to(&mut buf, compressed);
+            writer.write_all(&buf).await?;
+        }
+        writer.flush().await
+    }
+}
@@ -0,0 +1,65 @@
+// This module tests the asynchronous reads and writes over in-memory duplex streams.
+
+use crate::{PubParam, SerDes};
+use tokio::io::{duplex, AsyncWriteExt};
+
+#[tokio::test]
+async fn test_async_roundtrip() {
+    let pp = PubParam::default();
+    for &compressed in [true, false].iter() {
+        // a small buffer, so that the reader and the writer interleave
+        let (mut client, mut server) = duplex(64);
+        let (written, read) = tokio::join!(
+            pp.write_async(&mut client, compressed),
+            PubParam::read_async(&mut server, compressed)
+        );
+        written.unwrap();
+        assert_eq!(read.unwrap(), pp);
+
+        // the blob is the same as the sync one
+        let (mut client, mut server) = duplex(64);
+        let mut sync_buf = vec![];
+        pp.serialize(&mut sync_buf, compressed).unwrap();
+        let ((), read) = tokio::join!(
+            async {
+                client.write_all(&sync_buf).await.unwrap();
+            },
+            PubParam::read_async(&mut server, compressed)
+        );
+        assert_eq!(read.unwrap(), pp);
+    }
+}
+
+#[tokio::test]
+async fn test_async_errors() {
+    let pp = PubParam::default();
+    let bytes = pp.to_bytes(true).unwrap();
+
+    // an invalid header is rejected before the rest is read
+    let (mut client, mut server) = duplex(64);
+    client.write_all(&[0xFF, bytes[1]]).await.unwrap();
+    assert!(PubParam::read_async(&mut server, true).await.is_err());
+
+    // a truncated blob
+    let (mut client, mut server) = duplex(8192);
+    client.write_all(&bytes[..100]).await.unwrap();
+    drop(client);
+    assert!(PubParam::read_async(&mut server, true).await.is_err());
+
+    // an invalid element
+    let mut bad = bytes.clone();
+    bad[10] ^= 0xFF;
+    let (mut client, mut server) = duplex(8192);
+    client.write_all(&bad).await.unwrap();
+    assert!(PubParam::read_async(&mut server, true).await.is_err());
+
+    // the parameters with an invalid ciphersuite are not written
+    #[cfg(feature = "insecure-trapdoor")]
+    {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        let (pp, _) = PubParam::with_trapdoor(&mut StdRng::from_seed([0x42; 32]));
+        let (mut client, _server) = duplex(8192);
+        assert!(pp.write_async(&mut client, true).await.is_err());
+    }
+}
@@ -0,0 +1,78 @@
+// This module derives the seed of the public parameters from the output
+// of a publicly verifiable randomness beacon, e.g., a past block hash
+// or a drand round, supplied as a local record:
+//
+// `seed = SHA512(DOM_SEP_BEACON | I2OSP(len(source), 8) | source |
+//                I2OSP(round, 8) | I2OSP(len(value), 8) | value)`
+//
+// This module does not fetch nor authenticate the beacon output;
+// the record is checked against the beacon by the caller.
+
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::constants::{DOM_SEP_BEACON, ERR_SEED_TOO_SHORT};
+use crate::{GenericPubParam, PixelEngine};
+use sha2::{Digest, Sha512};
+
+/// A record of a beacon output.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BeaconRecord {
+    /// The label of the beacon, e.g., `"drand-mainnet"`.
+    pub source: Vec<u8>,
+    /// The round of the beacon output, e.g., a block height.
+    pub round: u64,
+    /// The beacon output of the round; must be at least 32 bytes.
+    pub value: Vec<u8>,
+}
+
+impl BeaconRecord {
+    /// Builds a record from a source label, a round and an output.
+    pub fn new(source: &[u8], round: u64, value: &[u8]) -> Self {
+        BeaconRecord {
+            source: source.to_vec(),
+            round,
+            value: value.to_vec(),
+        }
+    }
+
+    /// Canonically hashes the record into a seed.
+    /// Returns an error if the beacon output is shorter than 32 bytes.
+    pub fn seed(&self) -> Result<[u8; 64], String> {
+        if self.value.len() < 32 {
+            return Err(ERR_SEED_TOO_SHORT.to_owned());
+        }
+        let mut hasher = Sha512::new();
+        hasher.input(DOM_SEP_BEACON);
+        hasher.input((self.source.len() as u64).to_be_bytes());
+        hasher.input(&self.source);
+        hasher.input(self.round.to_be_bytes());
+        hasher.input((self.value.len() as u64).to_be_bytes());
+        hasher.input(&self.value);
+        let mut res = [0u8; 64];
+        res.copy_from_slice(hasher.result().as_slice());
+        Ok(res)
+    }
+}
+
+impl<E: PixelEngine> GenericPubParam<E> {
+    /// This function generates the public parameters from a beacon record:
+    /// `PubParam::init(record.seed(), ciphersuite)`.
+    /// It returns an error if the beacon output is shorter than 32 bytes,
+    /// or if the ciphersuite is not supported.
+    pub fn init_from_beacon(record: &BeaconRecord, ciphersuite: u8) -> Result<Self, String> {
+        Self::init(&record.seed()?, ciphersuite)
+    }
+}
+
+/// Re-checks offline that the public parameters are derived
+/// from the beacon record, with the ciphersuite of the parameters.
+pub fn verify_beacon_derivation<E: PixelEngine>(
+    pp: &GenericPubParam<E>,
+    record: &BeaconRecord,
+) -> bool {
+    match GenericPubParam::<E>::init_from_beacon(record, pp.ciphersuite()) {
+        Ok(p) => p == *pp,
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,37 @@
+// This module tests the parameter derivation from a beacon record.
+
+use crate::beacon::{verify_beacon_derivation, BeaconRecord};
+use crate::constants::VALID_CIPHERSUITE;
+use crate::PubParam;
+
+#[test]
+fn test_beacon() {
+    let record = BeaconRecord::new(b"drand-mainnet", 1_000_000, &[0x5a; 32]);
+    let pp = PubParam::init_from_beacon(&record, VALID_CIPHERSUITE[0]).unwrap();
+
+    // the derivation is deterministic and can be re-checked
+    let pp_seed = PubParam::init(&record.seed().unwrap(), VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp, pp_seed);
+    assert!(verify_beacon_derivation(&pp, &record));
+
+    // any change of the record changes the parameters
+    let records = [
+        BeaconRecord::new(b"drand-testnet", 1_000_000, &[0x5a; 32]),
+        BeaconRecord::new(b"drand-mainnet", 1_000_001, &[0x5a; 32]),
+        BeaconRecord::new(b"drand-mainnet", 1_000_000, &[0x5b; 32]),
+    ];
+    for r in records.iter() {
+        assert_ne!(r.seed().unwrap()[..], record.seed().unwrap()[..]);
+        assert!(!verify_beacon_derivation(&pp, r));
+    }
+
+    // so does the ciphersuite
+    let pp_other = PubParam::init_from_beacon(&record, VALID_CIPHERSUITE[1]).unwrap();
+    assert_ne!(pp, pp_other);
+    assert!(verify_beacon_derivation(&pp_other, &record));
+
+    // the beacon output must be at least 32 bytes
+    let record = BeaconRecord::new(b"block hash", 42, &[0x5a; 31]);
+    assert!(record.seed().is_err());
+    assert!(!verify_beacon_derivation(&pp, &record));
+}
@@ -0,0 +1,233 @@
+// This module implements a commit-reveal ceremony that derives the seed
+// of the public parameters from the contributions of several parties:
+// 1. each participant publishes `commit(participant, contribution)`
+// 2. once all commitments are collected, the participants reveal their
+//     contributions; each reveal is checked against its commitment
+// 3. the valid contributions are combined, in the order of the commitments:
+//     `seed = SHA512(DOM_SEP_CEREMONY_SEED | entry_1 | ... | entry_n)`
+//     where `entry_i = I2OSP(len(id_i), 8) | id_i | I2OSP(len(c_i), 8) | c_i`
+// 4. the public parameters are `PubParam::init(seed, ciphersuite)`;
+//     this closes the ceremony, which then accepts nothing more
+//
+// The seed is unpredictable as long as one participant that revealed
+// chose its contribution at random. Note that the last participant to
+// reveal may still withhold its contribution, so the record lists
+// the participants that did not reveal.
+
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::constants::{
+    DOM_SEP_CEREMONY_COMMIT, DOM_SEP_CEREMONY_SEED, ERR_CEREMONY_DUPLICATE, ERR_CEREMONY_EMPTY,
+    ERR_CEREMONY_FINALIZED, ERR_CEREMONY_MISMATCH, ERR_CEREMONY_PHASE, ERR_CEREMONY_RECORD,
+    ERR_CEREMONY_UNKNOWN, ERR_SEED_TOO_SHORT,
+};
+use crate::PubParam;
+use sha2::{Digest, Sha512};
+
+/// A commitment to a contribution.
+pub type Commitment = [u8; 64];
+
+/// Computes the commitment of a participant to a contribution:
+/// `SHA512(DOM_SEP_CEREMONY_COMMIT | I2OSP(len(id), 8) | id | I2OSP(len(c), 8) | c)`.
+pub fn commit(participant: &[u8], contribution: &[u8]) -> Commitment {
+    let mut hasher = Sha512::new();
+    hasher.input(DOM_SEP_CEREMONY_COMMIT);
+    input_entry(&mut hasher, participant, contribution);
+    to_array(hasher)
+}
+
+/// The state of a ceremony.
+/// Commitments are collected first; the first reveal closes the commitment phase,
+/// and a successful `finalize` closes the ceremony.
+#[derive(Clone, Debug)]
+pub struct Ceremony {
+    ciphersuite: u8,
+    entries: Vec<RecordEntry>,
+    phase: Phase,
+}
+
+// the phases of a ceremony, in order
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Phase {
+    Commit,
+    Reveal,
+    Finalized,
+}
+
+/// The record of a participant in a ceremony.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordEntry {
+    /// The identifier of the participant.
+    pub participant: Vec<u8>,
+    /// The published commitment.
+    pub commitment: Commitment,
+    /// The revealed contribution, if the participant revealed a valid one.
+    pub contribution: Option<Vec<u8>>,
+}
+
+/// A verifiable record of a ceremony: anyone can recompute the commitments,
+/// the seed and the public parameters from it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CeremonyRecord {
+    /// The ciphersuite id of the public parameters.
+    pub ciphersuite: u8,
+    /// The participants, in the order of their commitments.
+    pub entries: Vec<RecordEntry>,
+    /// The seed combined from the valid contributions.
+    pub seed: [u8; 64],
+    /// The fingerprint of the resulting public parameters.
+    pub fingerprint: [u8; 32],
+}
+
+impl Ceremony {
+    /// Starts a ceremony for the given ciphersuite.
+    pub fn new(ciphersuite: u8) -> Self {
+        Ceremony {
+            ciphersuite,
+            entries: Vec::new(),
+            phase: Phase::Commit,
+        }
+    }
+
+    /// Registers the commitment of a participant.
+    /// Returns an error if the participant has already committed,
+    /// or if the reveal phase has started.
+    pub fn add_commitment(
+        &mut self,
+        participant: &[u8],
+        commitment: Commitment,
+    ) -> Result<(), String> {
+        match self.phase {
+            Phase::Commit => (),
+            Phase::Reveal => return Err(ERR_CEREMONY_PHASE.to_owned()),
+            Phase::Finalized => return Err(ERR_CEREMONY_FINALIZED.to_owned()),
+        }
+        if self.entries.iter().any(|e| e.participant == participant) {
+            return Err(ERR_CEREMONY_DUPLICATE.to_owned());
+        }
+        self.entries.push(RecordEntry {
+            participant: participant.to_vec(),
+            commitment,
+            contribution: None,
+        });
+        Ok(())
+    }
+
+    /// Reveals the contribution of a participant.
+    /// Returns an error if the ceremony is finalized,
+    /// if the participant has not committed or has already revealed,
+    /// if the contribution is shorter than 32 bytes,
+    /// or if it does not match the commitment.
+    pub fn reveal(&mut self, participant: &[u8], contribution: &[u8]) -> Result<(), String> {
+        if self.phase == Phase::Finalized {
+            return Err(ERR_CEREMONY_FINALIZED.to_owned());
+        }
+        if contribution.len() < 32 {
+            return Err(ERR_SEED_TOO_SHORT.to_owned());
+        }
+        let entry = match self
+            .entries
+            .iter_mut()
+            .find(|e| e.participant == participant)
+        {
+            Some(e) => e,
+            None => return Err(ERR_CEREMONY_UNKNOWN.to_owned()),
+        };
+        if entry.contribution.is_some() {
+            return Err(ERR_CEREMONY_DUPLICATE.to_owned());
+        }
+        if commit(participant, contribution) != entry.commitment {
+            return Err(ERR_CEREMONY_MISMATCH.to_owned());
+        }
+        entry.contribution = Some(contribution.to_vec());
+        self.phase = Phase::Reveal;
+        Ok(())
+    }
+
+    /// Combines the valid contributions into a seed, and generates
+    /// the public parameters along with the record of the ceremony.
+    /// On success the ceremony is closed: `reveal`, `add_commitment`
+    /// and `finalize` then return an error.
+    /// Returns an error if the ceremony is already finalized,
+    /// if no valid contribution was revealed,
+    /// or if the ciphersuite is not supported.
+    pub fn finalize(&mut self) -> Result<(PubParam, CeremonyRecord), String> {
+        if self.phase == Phase::Finalized {
+            return Err(ERR_CEREMONY_FINALIZED.to_owned());
+        }
+        let seed = combine(&self.entries)?;
+        let pp = PubParam::init(&seed, self.ciphersuite)?;
+        let record = CeremonyRecord {
+            ciphersuite: self.ciphersuite,
+            entries: self.entries.clone(),
+            seed,
+            fingerprint: pp.fingerprint(),
+        };
+        self.phase = Phase::Finalized;
+        Ok((pp, record))
+    }
+}
+
+impl CeremonyRecord {
+    /// Re-checks the record: each revealed contribution against its commitment,
+    /// the seed against the contributions, and the fingerprint against the
+    /// regenerated public parameters, which are returned.
+    pub fn verify(&self) -> Result<PubParam, String> {
+        for e in self.entries.iter() {
+            if let Some(c) = &e.contribution {
+                if c.len() < 32 || commit(&e.participant, c) != e.commitment {
+                    return Err(ERR_CEREMONY_MISMATCH.to_owned());
+                }
+            }
+        }
+        if combine(&self.entries)?[..] != self.seed[..] {
+            return Err(ERR_CEREMONY_RECORD.to_owned());
+        }
+        let pp = PubParam::init(&self.seed, self.ciphersuite)?;
+        if pp.fingerprint() != self.fingerprint {
+            return Err(ERR_CEREMONY_RECORD.to_owned());
+        }
+        Ok(pp)
+    }
+
+    /// Returns the participants that committed but did not reveal.
+    pub fn withheld(&self) -> Vec<&[u8]> {
+        self.entries
+            .iter()
+            .filter(|e| e.contribution.is_none())
+            .map(|e| e.participant.as_ref())
+            .collect()
+    }
+}
+
+/// Combines the revealed contributions into a seed.
+fn combine(entries: &[RecordEntry]) -> Result<[u8; 64], String> {
+    let mut hasher = Sha512::new();
+    hasher.input(DOM_SEP_CEREMONY_SEED);
+    let mut count = 0;
+    for e in entries.iter() {
+        if let Some(c) = &e.contribution {
+            input_entry(&mut hasher, &e.participant, c);
+            count += 1;
+        }
+    }
+    if count == 0 {
+        return Err(ERR_CEREMONY_EMPTY.to_owned());
+    }
+    Ok(to_array(hasher))
+}
+
+/// Hashes `I2OSP(len(id), 8) | id | I2OSP(len(c), 8) | c`.
+fn input_entry(hasher: &mut Sha512, participant: &[u8], contribution: &[u8]) {
+    hasher.input((participant.len() as u64).to_be_bytes());
+    hasher.input(participant);
+    hasher.input((contribution.len() as u64).to_be_bytes());
+    hasher.input(contribution);
+}
+
+fn to_array(hasher: Sha512) -> [u8; 64] {
+    let mut res = [0u8; 64];
+    res.copy_from_slice(hasher.result().as_slice());
+    res
+}
@@ -0,0 +1,112 @@
+// This module tests the seed ceremony end-to-end with simulated parties.
+
+use crate::ceremony::{commit, Ceremony};
+use crate::constants::{ERR_CEREMONY_FINALIZED, VALID_CIPHERSUITE};
+use crate::PubParam;
+
+// three parties with distinct contributions
+const PARTIES: [(&[u8], [u8; 32]); 3] = [
+    (b"alice", [0x11; 32]),
+    (b"bob", [0x22; 32]),
+    (b"carol", [0x33; 32]),
+];
+
+#[test]
+fn test_ceremony() {
+    let mut ceremony = Ceremony::new(VALID_CIPHERSUITE[0]);
+    for (id, c) in PARTIES.iter() {
+        ceremony.add_commitment(id, commit(id, c)).unwrap();
+    }
+    for (id, c) in PARTIES.iter() {
+        ceremony.reveal(id, c).unwrap();
+    }
+    let (pp, record) = ceremony.finalize().unwrap();
+    assert!(record.withheld().is_empty());
+
+    // anyone can re-check the record
+    assert_eq!(record.verify().unwrap(), pp);
+    let pp_seed = PubParam::init(&record.seed, VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp, pp_seed);
+
+    // a tampered record is rejected
+    let mut bad = record.clone();
+    bad.seed[0] ^= 1;
+    assert!(bad.verify().is_err());
+    let mut bad = record.clone();
+    bad.entries[1].contribution = Some(vec![0x44; 32]);
+    assert!(bad.verify().is_err());
+    let mut bad = record;
+    bad.fingerprint[0] ^= 1;
+    assert!(bad.verify().is_err());
+}
+
+#[test]
+fn test_ceremony_invalid_reveals() {
+    let mut ceremony = Ceremony::new(VALID_CIPHERSUITE[0]);
+    for (id, c) in PARTIES.iter() {
+        ceremony.add_commitment(id, commit(id, c)).unwrap();
+    }
+    // a participant cannot commit twice
+    assert!(ceremony.add_commitment(b"alice", [0u8; 64]).is_err());
+
+    // nothing revealed yet
+    assert!(ceremony.finalize().is_err());
+
+    // a reveal must match the commitment
+    assert!(ceremony.reveal(b"alice", &[0x22; 32]).is_err());
+    assert!(ceremony.reveal(b"dave", &[0x44; 32]).is_err());
+    assert!(ceremony.reveal(b"alice", &[0x11; 31]).is_err());
+    ceremony.reveal(b"alice", &[0x11; 32]).unwrap();
+    assert!(ceremony.reveal(b"alice", &[0x11; 32]).is_err());
+
+    // the commitment phase is closed
+    assert!(ceremony
+        .add_commitment(b"dave", commit(b"dave", &[0x44; 32]))
+        .is_err());
+
+    // bob reveals, carol withholds
+    ceremony.reveal(b"bob", &[0x22; 32]).unwrap();
+    let (pp, record) = ceremony.finalize().unwrap();
+    assert_eq!(record.withheld(), vec![&b"carol"[..]]);
+    assert_eq!(record.verify().unwrap(), pp);
+
+    // the result depends on the set of valid contributions
+    let mut ceremony_full = Ceremony::new(VALID_CIPHERSUITE[0]);
+    for (id, c) in PARTIES.iter() {
+        ceremony_full.add_commitment(id, commit(id, c)).unwrap();
+    }
+    for (id, c) in PARTIES.iter() {
+        ceremony_full.reveal(id, c).unwrap();
+    }
+    let (pp_full, _) = ceremony_full.finalize().unwrap();
+    assert_ne!(pp.fingerprint(), pp_full.fingerprint());
+}
+
+#[test]
+fn test_ceremony_finalized() {
+    let mut ceremony = Ceremony::new(VALID_CIPHERSUITE[0]);
+    for (id, c) in PARTIES.iter() {
+        ceremony.add_commitment(id, commit(id, c)).unwrap();
+    }
+    ceremony.reveal(b"alice", &[0x11; 32]).unwrap();
+    let (pp, record) = ceremony.finalize().unwrap();
+
+    // a late reveal is rejected, even if it matches its commitment
+    assert_eq!(
+        ceremony.reveal(b"bob", &[0x22; 32]),
+        Err(ERR_CEREMONY_FINALIZED.to_owned())
+    );
+    assert_eq!(
+        ceremony.add_commitment(b"dave", commit(b"dave", &[0x44; 32])),
+        Err(ERR_CEREMONY_FINALIZED.to_owned())
+    );
+    // and so is a second finalize
+    assert_eq!(
+        ceremony.finalize().unwrap_err(),
+        ERR_CEREMONY_FINALIZED.to_owned()
+    );
+
+    // the record is unchanged
+    assert_eq!(record.withheld(), vec![&b"bob"[..], &b"carol"[..]]);
+    assert_eq!(record.verify().unwrap(), pp);
+}
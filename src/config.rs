@@ -0,0 +1,128 @@
+// This module defines the configuration of the parameter generation.
+// The default configuration reproduces `PubParam::init`.
+
+use crate::alloc::vec::Vec;
+use crate::constants::{CONST_D, DOM_SEP_PARAM_GEN, VALID_CIPHERSUITE};
+use crate::kdf::KdfHash;
+
+/// The default info label for `h`, and the default prefix of
+/// the info labels for `h_0, ..., h_d`.
+pub const DEFAULT_INFO_LABEL: &[u8] = b"H2G_h";
+
+/// The configuration of the parameter generation:
+/// * dst: the domain separation tag, i.e., the salt of HKDF-Extract
+/// * h_info: the info label of HKDF-Expand for `h`
+/// * hlist_info: the prefix of the info labels for `h_i`;
+///     the label is `hlist_info | I2OSP(i, 1)`
+/// * depth: the depth of the time tree, at most `CONST_D`
+/// * ciphersuite: the ciphersuite id
+/// * hash: the hash function of the HKDF; it is selected by the
+///     ciphersuite id, and if set, it must match the selected one
+///
+/// Chains that share a seed source obtain independent parameters
+/// by using distinct domain separation tags.
+/// Note that only the ciphersuite id and the depth are recorded in
+/// the serialized parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamGenConfig {
+    dst: Vec<u8>,
+    h_info: Vec<u8>,
+    hlist_info: Vec<u8>,
+    depth: usize,
+    ciphersuite: u8,
+    hash: Option<KdfHash>,
+}
+
+impl Default for ParamGenConfig {
+    fn default() -> Self {
+        ParamGenConfig {
+            dst: DOM_SEP_PARAM_GEN.as_bytes().to_vec(),
+            h_info: DEFAULT_INFO_LABEL.to_vec(),
+            hlist_info: DEFAULT_INFO_LABEL.to_vec(),
+            depth: CONST_D,
+            ciphersuite: VALID_CIPHERSUITE[0],
+            hash: None,
+        }
+    }
+}
+
+impl ParamGenConfig {
+    /// Returns the default configuration, with which
+    /// `init_with_config` is the same as `init`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the domain separation tag.
+    pub fn dst(mut self, dst: &[u8]) -> Self {
+        self.dst = dst.to_vec();
+        self
+    }
+
+    /// Sets the info label for `h`.
+    pub fn h_info(mut self, info: &[u8]) -> Self {
+        self.h_info = info.to_vec();
+        self
+    }
+
+    /// Sets the prefix of the info labels for `h_0, ..., h_d`.
+    pub fn hlist_info(mut self, info: &[u8]) -> Self {
+        self.hlist_info = info.to_vec();
+        self
+    }
+
+    /// Sets the depth of the time tree.
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets the ciphersuite id.
+    pub fn ciphersuite(mut self, ciphersuite: u8) -> Self {
+        self.ciphersuite = ciphersuite;
+        self
+    }
+
+    /// Sets the hash function of the HKDF. The parameter generation
+    /// returns an error if it is not the one selected by the ciphersuite id,
+    /// since the serialized parameters only record the ciphersuite id.
+    pub fn hash(mut self, hash: KdfHash) -> Self {
+        self.hash = Some(hash);
+        self
+    }
+
+    /// Returns the domain separation tag.
+    pub fn get_dst(&self) -> &[u8] {
+        &self.dst
+    }
+
+    /// Returns the info label for `h`.
+    pub fn get_h_info(&self) -> &[u8] {
+        &self.h_info
+    }
+
+    /// Returns the prefix of the info labels for `h_0, ..., h_d`.
+    pub fn get_hlist_info(&self) -> &[u8] {
+        &self.hlist_info
+    }
+
+    /// Returns the depth of the time tree.
+    pub fn get_depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the ciphersuite id.
+    pub fn get_ciphersuite(&self) -> u8 {
+        self.ciphersuite
+    }
+
+    /// Returns the info label for `h_i`, i.e., `hlist_info | I2OSP(i, 1)`.
+    pub fn hlist_label(&self, i: usize) -> Vec<u8> {
+        [&self.hlist_info[..], &[i as u8][..]].concat()
+    }
+
+    /// Returns the hash function that is explicitly set, if any.
+    pub fn get_hash(&self) -> Option<KdfHash> {
+        self.hash
+    }
+}
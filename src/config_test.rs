@@ -0,0 +1,126 @@
+// This module tests the parameter generation with a configuration.
+
+use crate::config::ParamGenConfig;
+use crate::constants::{ERR_CONFIG_HASH, SHA512_IV, VALID_CIPHERSUITE};
+use crate::kdf::KdfHash;
+use crate::{LazyPubParam, PubParam, CONST_D};
+
+#[test]
+fn test_default_config() {
+    // the default configuration reproduces `init`
+    let pp = PubParam::init_with_config(SHA512_IV.as_ref(), &ParamGenConfig::new()).unwrap();
+    assert_eq!(pp, PubParam::default());
+
+    // so does an explicit configuration with the default values
+    let config = ParamGenConfig::new()
+        .dst(b"Pixel public parameter generation")
+        .h_info(b"H2G_h")
+        .hlist_info(b"H2G_h")
+        .depth(CONST_D)
+        .ciphersuite(VALID_CIPHERSUITE[0])
+        .hash(KdfHash::Sha512);
+    let pp = PubParam::init_with_config(SHA512_IV.as_ref(), &config).unwrap();
+    assert_eq!(pp, PubParam::default());
+}
+
+#[test]
+fn test_config_dst() {
+    // distinct domain separation tags yield independent parameters
+    let config_a = ParamGenConfig::new().dst(b"chain A");
+    let config_b = ParamGenConfig::new().dst(b"chain B");
+    let pp_a = PubParam::init_with_config(SHA512_IV.as_ref(), &config_a).unwrap();
+    let pp_b = PubParam::init_with_config(SHA512_IV.as_ref(), &config_b).unwrap();
+    assert_ne!(pp_a.h(), pp_b.h());
+    assert_ne!(pp_a.hlist()[0], pp_b.hlist()[0]);
+    assert_ne!(pp_a.h(), PubParam::default().h());
+
+    // so do distinct info labels
+    let config = ParamGenConfig::new().hlist_info(b"H2G_hlist");
+    let pp = PubParam::init_with_config(SHA512_IV.as_ref(), &config).unwrap();
+    assert_eq!(pp.h(), PubParam::default().h());
+    assert_ne!(pp.hlist()[0], PubParam::default().hlist()[0]);
+}
+
+#[test]
+fn test_config_depth() {
+    let config = ParamGenConfig::new().depth(4);
+    let pp = PubParam::init_with_config(SHA512_IV.as_ref(), &config).unwrap();
+    assert_eq!(pp.depth(), 4);
+    let def_pp = PubParam::default();
+    assert_eq!(pp.hlist()[..5], def_pp.hlist()[..5]);
+
+    // the blob records the depth
+    let bytes = pp.to_bytes(true).unwrap();
+    assert_eq!(bytes.len(), pp.size(true));
+    assert_eq!(bytes[1], 4);
+    let pp_recover = PubParam::from_bytes(&bytes, true).unwrap();
+    assert_eq!(pp, pp_recover);
+
+    // the depth cannot exceed CONST_D
+    let config = ParamGenConfig::new().depth(CONST_D + 1);
+    assert!(PubParam::init_with_config(SHA512_IV.as_ref(), &config).is_err());
+}
+
+#[test]
+fn test_config_ciphersuite() {
+    // the hash function defaults to the one selected by the ciphersuite
+    let config = ParamGenConfig::new().ciphersuite(VALID_CIPHERSUITE[2]);
+    let pp = PubParam::init_with_config(SHA512_IV.as_ref(), &config).unwrap();
+    let pp_init = PubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[2]).unwrap();
+    assert_eq!(pp, pp_init);
+
+    // the hash function, if set, must match the ciphersuite
+    let config = config.hash(KdfHash::Sha256);
+    let pp = PubParam::init_with_config(SHA512_IV.as_ref(), &config).unwrap();
+    assert_eq!(pp, pp_init);
+    let config = ParamGenConfig::new()
+        .ciphersuite(VALID_CIPHERSUITE[0])
+        .hash(KdfHash::Blake2b);
+    assert_eq!(
+        PubParam::init_with_config(SHA512_IV.as_ref(), &config).unwrap_err(),
+        ERR_CONFIG_HASH
+    );
+    assert!(LazyPubParam::init_with_config(SHA512_IV.as_ref(), &config).is_err());
+
+    // an invalid ciphersuite is rejected
+    let config = ParamGenConfig::new().ciphersuite(0xFF);
+    assert!(PubParam::init_with_config(SHA512_IV.as_ref(), &config).is_err());
+}
+
+#[test]
+fn test_truncate() {
+    let def_pp = PubParam::default();
+    let bytes_full = def_pp.to_bytes(true).unwrap();
+    for &depth in [0, 1, 16, CONST_D].iter() {
+        // truncating the full set is the same as generating with a smaller depth
+        let pp = def_pp.truncate(depth).unwrap();
+        let config = ParamGenConfig::new().depth(depth);
+        let pp_config = PubParam::init_with_config(SHA512_IV.as_ref(), &config).unwrap();
+        assert_eq!(pp, pp_config);
+        assert_eq!(pp.depth(), depth);
+        assert_eq!(pp.g2(), def_pp.g2());
+        assert_eq!(pp.h(), def_pp.h());
+        assert_eq!(pp.hlist()[..=depth], def_pp.hlist()[..=depth]);
+
+        // the blob is a prefix of the full blob, up to the depth byte
+        let bytes = pp.to_bytes(true).unwrap();
+        assert_eq!(bytes.len(), pp.size(true));
+        assert_eq!(bytes[0], bytes_full[0]);
+        assert_eq!(bytes[1], depth as u8);
+        assert_eq!(bytes[2..], bytes_full[2..bytes.len()]);
+        let pp_recover = PubParam::from_bytes(&bytes, true).unwrap();
+        assert_eq!(pp, pp_recover);
+        assert_eq!(pp.fingerprint(), pp_config.fingerprint());
+
+        // truncation composes
+        assert_eq!(
+            pp.truncate(depth / 2).unwrap(),
+            def_pp.truncate(depth / 2).unwrap()
+        );
+    }
+
+    // the depth can only shrink
+    let pp = def_pp.truncate(16).unwrap();
+    assert!(pp.truncate(17).is_err());
+    assert!(def_pp.truncate(CONST_D + 1).is_err());
+}
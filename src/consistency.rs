@@ -0,0 +1,134 @@
+// This module checks that a set of parameter files are mutually consistent.
+// Each file is loaded, compressed or uncompressed as given by its length,
+// and compared against the first file that loads: the report lists the
+// elements each file disagrees on, and groups the files by fingerprint.
+
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::serdes::serialized_len;
+use crate::PubParam;
+use pairing::bls12_381::Bls12;
+use std::path::{Path, PathBuf};
+
+/// An element of the public parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamField {
+    /// The ciphersuite id.
+    Ciphersuite,
+    /// The depth.
+    Depth,
+    /// The generator `g2`.
+    G2,
+    /// The element `h`.
+    H,
+    /// The element `h_i` of `hlist`.
+    Hlist(usize),
+}
+
+/// The elements on which a file disagrees with the reference file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    /// The path of the file.
+    pub path: PathBuf,
+    /// The elements that differ; `hlist` is compared up to the smaller depth.
+    pub fields: Vec<ParamField>,
+}
+
+/// The outcome of `check_all`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConsistencyReport {
+    /// The file the others are compared to, i.e., the first file that loads.
+    pub reference: Option<PathBuf>,
+    /// The files that load, grouped by fingerprint,
+    /// in the order of their first appearance.
+    pub groups: Vec<([u8; 32], Vec<PathBuf>)>,
+    /// The files that disagree with the reference.
+    pub mismatches: Vec<Mismatch>,
+    /// The files that fail to load, along with the error.
+    pub errors: Vec<(PathBuf, String)>,
+}
+
+impl ConsistencyReport {
+    /// Returns true if all files load, and hold the same parameters.
+    pub fn is_consistent(&self) -> bool {
+        self.errors.is_empty() && self.groups.len() <= 1
+    }
+}
+
+/// Loads a parameter file; the file is compressed if its length
+/// is that of a compressed blob with the depth of the file.
+/// Returns the parameters and whether the file is compressed.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<(PubParam, bool), String> {
+    let bytes = match std::fs::read(path) {
+        Ok(b) => b,
+        Err(e) => return Err(e.to_string()),
+    };
+    let compressed =
+        bytes.len() >= 2 && bytes.len() == serialized_len::<Bls12>(bytes[1] as usize, true);
+    let pp = PubParam::from_bytes(&bytes, compressed)?;
+    Ok((pp, compressed))
+}
+
+/// Returns the elements on which two parameter sets disagree.
+pub fn diff(a: &PubParam, b: &PubParam) -> Vec<ParamField> {
+    let mut fields = Vec::new();
+    if a.ciphersuite() != b.ciphersuite() {
+        fields.push(ParamField::Ciphersuite);
+    }
+    if a.depth() != b.depth() {
+        fields.push(ParamField::Depth);
+    }
+    if a.g2() != b.g2() {
+        fields.push(ParamField::G2);
+    }
+    if a.h() != b.h() {
+        fields.push(ParamField::H);
+    }
+    for (i, (x, y)) in a.hlist_ref().iter().zip(b.hlist_ref()).enumerate() {
+        if x != y {
+            fields.push(ParamField::Hlist(i));
+        }
+    }
+    fields
+}
+
+/// Loads each file, and reports the files that fail to load,
+/// the elements on which each file disagrees with the first file that loads,
+/// and the groups of files with the same fingerprint.
+pub fn check_all<P: AsRef<Path>>(paths: &[P]) -> ConsistencyReport {
+    let mut report = ConsistencyReport::default();
+    let mut reference: Option<PubParam> = None;
+    for path in paths.iter() {
+        let path = path.as_ref();
+        let pp = match load(path) {
+            Ok((pp, _)) => pp,
+            Err(e) => {
+                report.errors.push((path.to_path_buf(), e));
+                continue;
+            }
+        };
+
+        let fingerprint = pp.fingerprint();
+        match report.groups.iter_mut().find(|(f, _)| *f == fingerprint) {
+            Some((_, files)) => files.push(path.to_path_buf()),
+            None => report.groups.push((fingerprint, vec![path.to_path_buf()])),
+        }
+
+        match &reference {
+            Some(r) => {
+                let fields = diff(r, &pp);
+                if !fields.is_empty() {
+                    report.mismatches.push(Mismatch {
+                        path: path.to_path_buf(),
+                        fields,
+                    });
+                }
+            }
+            None => {
+                report.reference = Some(path.to_path_buf());
+                reference = Some(pp);
+            }
+        }
+    }
+    report
+}
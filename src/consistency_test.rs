@@ -0,0 +1,60 @@
+// This module tests the consistency check of parameter files.
+
+use crate::consistency::{check_all, load, ParamField};
+use crate::constants::VALID_CIPHERSUITE;
+use crate::test_util::write_file;
+use crate::PubParam;
+use std::path::PathBuf;
+
+#[test]
+fn test_check_all() {
+    let def_pp = PubParam::default();
+    let other = PubParam::init(&[0x42; 32], VALID_CIPHERSUITE[0]).unwrap();
+
+    let a = write_file("consistency", "a", &def_pp.to_bytes(true).unwrap());
+    let b = write_file("consistency", "b", &def_pp.to_bytes(false).unwrap());
+    let c = write_file("consistency", "c", &other.to_bytes(true).unwrap());
+    let d = write_file(
+        "consistency",
+        "d",
+        &def_pp.truncate(4).unwrap().to_bytes(false).unwrap(),
+    );
+    let e = write_file("consistency", "e", &[0u8; 10]);
+    let missing = std::env::temp_dir().join("pixel_param_consistency_missing");
+
+    // compressed and uncompressed files load alike
+    assert_eq!(load(&a).unwrap(), (def_pp.clone(), true));
+    assert_eq!(load(&b).unwrap(), (def_pp.clone(), false));
+
+    let report = check_all(&[&a, &b]);
+    assert!(report.is_consistent());
+    assert_eq!(report.groups.len(), 1);
+    assert_eq!(report.groups[0].0, def_pp.fingerprint());
+
+    let report = check_all(&[&a, &b, &c, &d, &e, &missing]);
+    assert!(!report.is_consistent());
+    assert_eq!(report.reference, Some(a.clone()));
+    let groups: Vec<Vec<PathBuf>> = report.groups.iter().map(|g| g.1.clone()).collect();
+    assert_eq!(
+        groups,
+        vec![vec![a.clone(), b.clone()], vec![c.clone()], vec![d.clone()]]
+    );
+
+    // c differs in h and every hlist entry, d only in the depth
+    assert_eq!(report.mismatches.len(), 2);
+    assert_eq!(report.mismatches[0].path, c);
+    let fields = &report.mismatches[0].fields;
+    assert_eq!(fields[0], ParamField::H);
+    assert_eq!(fields.len(), 1 + def_pp.depth() + 1);
+    assert!(fields.contains(&ParamField::Hlist(7)));
+    assert_eq!(report.mismatches[1].path, d);
+    assert_eq!(report.mismatches[1].fields, vec![ParamField::Depth]);
+
+    // e and the missing file fail to load
+    let errors: Vec<PathBuf> = report.errors.iter().map(|e| e.0.clone()).collect();
+    assert_eq!(errors, vec![e.clone(), missing]);
+
+    for p in [a, b, c, d, e].iter() {
+        std::fs::remove_file(p).unwrap();
+    }
+}
@@ -4,16 +4,77 @@
 /// enough in practise.
 pub const CONST_D: usize = 32;
 
-/// Currently, ciphersuite identifier must be either 0 or 1.
-/// The maps between CSID and actual parameters is TBD.
+/// Currently, ciphersuite identifier must be between 0 and 7.
+/// The ciphersuite identifier selects the hash function of the HKDF:
+/// SHA512 for 0 and 1, SHA256 for 2 and 3, BLAKE2b for 4 and 5,
+/// and SHA3-512 for 6 and 7.
+/// The maps between CSID and the other parameters is TBD.
 /// Additional ciphersuite identifiers may be added later.
-pub const VALID_CIPHERSUITE: [u8; 2] = [0, 1];
+#[cfg(not(feature = "no_swap"))]
+pub const VALID_CIPHERSUITE: [u8; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+
+/// With feature `no_swap`, the highest bit of the ciphersuite identifier
+/// is set to record the group assignment, so that a blob generated
+/// with one assignment cannot be loaded with the other.
+/// The ciphersuite identifier must be between 0x80 and 0x87.
+#[cfg(feature = "no_swap")]
+pub const VALID_CIPHERSUITE: [u8; 8] = [
+    NO_SWAP_FLAG,
+    NO_SWAP_FLAG | 1,
+    NO_SWAP_FLAG | 2,
+    NO_SWAP_FLAG | 3,
+    NO_SWAP_FLAG | 4,
+    NO_SWAP_FLAG | 5,
+    NO_SWAP_FLAG | 6,
+    NO_SWAP_FLAG | 7,
+];
+
+/// The bit of the ciphersuite identifier that records that
+/// the groups are not switched.
+pub const NO_SWAP_FLAG: u8 = 0x80;
+
+/// INSECURE: the ciphersuite identifier of the parameters with a trapdoor.
+/// It is not a valid ciphersuite identifier, so that such parameters
+/// can neither be serialized nor deserialized.
+#[cfg(feature = "insecure-trapdoor")]
+pub const TRAPDOOR_CIPHERSUITE: u8 = 0x70 | (VALID_CIPHERSUITE[0] & NO_SWAP_FLAG);
 
 // prefix of hash_to_group to generate public parameters
 pub const DOM_SEP_PARAM_GEN: &str = "Pixel public parameter generation";
+// prefixes of the hashes in the seed ceremony
+pub const DOM_SEP_CEREMONY_COMMIT: &str = "Pixel ceremony commitment";
+pub const DOM_SEP_CEREMONY_SEED: &str = "Pixel ceremony seed";
+// prefix of the hash of a beacon record
+pub const DOM_SEP_BEACON: &str = "Pixel beacon seed";
 // Error messages
 pub const ERR_SEED_TOO_SHORT: &str = "The seed length is too short";
+pub const ERR_SEED_CONSTANT: &str = "The seed is a single repeated byte";
+pub const ERR_SEED_REPEATING: &str = "The seed repeats a short pattern";
+pub const ERR_SEED_KNOWN: &str = "The seed is a known test seed";
 pub const ERR_CIPHERSUITE: &str = "Invalid ciphersuite ID";
+pub const ERR_HKDF: &str = "Error getting output from HKDF";
+pub const ERR_CONFIG_DEPTH: &str = "The depth exceeds the maximum depth";
+pub const ERR_CONFIG_HASH: &str = "The hash function does not match the ciphersuite ID";
+pub const ERR_TRUNCATE_DEPTH: &str = "The new depth exceeds the current depth";
+pub const ERR_CEREMONY_DUPLICATE: &str =
+    "Ceremony err: the participant has already committed or revealed";
+pub const ERR_CEREMONY_UNKNOWN: &str = "Ceremony err: the participant has not committed";
+pub const ERR_CEREMONY_MISMATCH: &str =
+    "Ceremony err: the contribution does not match the commitment";
+pub const ERR_CEREMONY_PHASE: &str = "Ceremony err: the commitment phase is closed";
+pub const ERR_CEREMONY_FINALIZED: &str = "Ceremony err: the ceremony is finalized";
+pub const ERR_CEREMONY_EMPTY: &str = "Ceremony err: no valid contribution";
+pub const ERR_CEREMONY_RECORD: &str = "Ceremony err: the record is inconsistent";
+pub const ERR_TIME_DEPTH: &str = "Time err: the depth of the tree is invalid";
+pub const ERR_TIME_STAMP: &str = "Time err: the time stamp is out of the tree";
+pub const ERR_TIME_PATH: &str = "Time err: the path is invalid";
+pub const ERR_HLIST_INDEX: &str = "The index exceeds the depth";
+pub const ERR_DEPTH: &str = "Deserialization err: the depth exceeds the maximum depth!";
+pub const ERR_SERIAL_LEN: &str = "Deserialization err: the length of the blob is incorrect";
+pub const ERR_GROUP_ELEMENT: &str = "Deserialization err: invalid group element";
+pub const ERR_GT_ELEMENT: &str = "Deserialization err: invalid target group element";
+pub const ERR_GT_MISMATCH: &str =
+    "Deserialization err: the pairing value does not match the parameters";
 // pub const ERR_COMPRESS: &str = "Compressness does not match";
 
 /// The seed we will be using for the default public parameter generation
@@ -1,11 +1,24 @@
-use crate::{PubParam, SerDes, PP_LEN_UNCOMPRESSED};
+use crate::PubParam;
+#[cfg(not(feature = "no_swap"))]
+use crate::PP_LEN_UNCOMPRESSED;
 
 /// The default public parameters are generated
 /// with seed = SHA512_IV
-impl std::default::Default for PubParam {
+/// With feature `no_swap` there is no pre-computed parameter,
+/// and it is generated on the fly.
+#[cfg(feature = "no_swap")]
+impl core::default::Default for PubParam {
     fn default() -> Self {
-        let mut buf = DEFAULT_PARAM_STR;
-        let res = PubParam::deserialize(&mut buf[..].as_ref(), false);
+        PubParam::init_without_seed()
+    }
+}
+
+/// The default public parameters are generated
+/// with seed = SHA512_IV
+#[cfg(not(feature = "no_swap"))]
+impl core::default::Default for PubParam {
+    fn default() -> Self {
+        let res = PubParam::from_bytes(DEFAULT_PARAM_STR.as_ref(), false);
         assert!(
             res.is_ok(),
             "Error deserialize public parameter from the default string!"
@@ -15,6 +28,7 @@ impl std::default::Default for PubParam {
 }
 
 /// this is the pre-computed public parameter (with default seed) in a serialized form.
+#[cfg(not(feature = "no_swap"))]
 const DEFAULT_PARAM_STR: [u8; PP_LEN_UNCOMPRESSED] = [
     0x00, 0x20, 0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c, 0x4f, 0xa9,
     0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05, 0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b,
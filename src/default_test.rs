@@ -1,4 +1,4 @@
-use crate::constants::SHA512_IV;
+use crate::constants::{SHA512_IV, VALID_CIPHERSUITE};
 use crate::PubParam;
 
 // basic sanity check to see if the default parameters are
@@ -7,7 +7,7 @@ use crate::PubParam;
 fn test_default_parameters() {
     let def_pp = PubParam::default();
     let pp_without_seed = PubParam::init_without_seed();
-    let pp_with_seed = PubParam::init(SHA512_IV.as_ref(), 0).unwrap();
+    let pp_with_seed = PubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[0]).unwrap();
 
     // // The following code generate serialize the default parameters for testing
     // use pairing::serdes::SerDes;
@@ -23,3 +23,37 @@ fn test_default_parameters() {
     assert_eq!(def_pp, pp_without_seed, "default parameter is not correct!");
     assert_eq!(def_pp, pp_with_seed, "default parameter is not correct!");
 }
+
+// the fingerprint identifies a parameter set
+#[test]
+fn test_fingerprint() {
+    let def_pp = PubParam::default();
+    let pp_with_seed = PubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[0]).unwrap();
+    let pp_other_csid = PubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[1]).unwrap();
+
+    assert_eq!(def_pp.fingerprint(), pp_with_seed.fingerprint());
+    assert_ne!(def_pp.fingerprint(), pp_other_csid.fingerprint());
+}
+
+// the affine copies match the projective elements
+#[test]
+fn test_affine_accessors() {
+    use pairing::CurveProjective;
+
+    let def_pp = PubParam::default();
+    assert_eq!(*def_pp.g2_affine(), def_pp.g2().into_affine());
+    assert_eq!(*def_pp.h_affine(), def_pp.h().into_affine());
+    assert_eq!(def_pp.hlist_ref(), &def_pp.hlist()[..]);
+    assert_eq!(def_pp.hlist_affine().len(), def_pp.depth() + 1);
+    for (a, e) in def_pp.hlist_affine().iter().zip(def_pp.hlist_ref()) {
+        assert_eq!(*a, e.into_affine());
+    }
+
+    // so do those of the decoded and truncated parameters
+    let bytes = def_pp.to_bytes(true).unwrap();
+    let pp = PubParam::from_bytes(&bytes, true).unwrap();
+    assert_eq!(pp.hlist_affine(), def_pp.hlist_affine());
+    let pp = def_pp.truncate(4).unwrap();
+    assert_eq!(pp.hlist_ref().len(), 5);
+    assert_eq!(pp.hlist_affine(), &def_pp.hlist_affine()[..5]);
+}
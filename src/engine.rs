@@ -0,0 +1,130 @@
+// This module defines the pairing engines that the public parameters
+// can be instantiated with.
+
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::constants::{ERR_GT_ELEMENT, ERR_SERIAL_LEN};
+use ff::{Field, PrimeField};
+use pairing::bls12_381::{Bls12, Fq, Fq12, Fq2, Fq6, FqRepr};
+use pairing::hash_to_curve::HashToCurve;
+use pairing::{CurveAffine, CurveProjective, Engine};
+
+/// A pairing engine with a Pixel group assignment.
+/// `PixelG1` hosts `h` and `hlist`, and therefore must support hash to curve;
+/// `PixelG2` hosts the generator `g2`.
+/// The sizes of the serialized parameters are derived from the
+/// encoded point sizes of these two groups.
+///
+/// The pairing itself is provided by `Engine`, so that two assignments
+/// may share a curve. `pairing-plus` provides BLS12-381 only,
+/// hence `Bls12` is the only implementation shipped by this crate.
+pub trait PixelEngine: Sized + Clone + 'static {
+    /// The pairing engine of both groups.
+    type Engine: Engine;
+    /// The group of `h` and `hlist`.
+    type PixelG1: CurveProjective + HashToCurve;
+    /// The group of the generator `g2`.
+    type PixelG2: CurveProjective;
+
+    /// Computes the product of the Miller loops of `e(x_i, y_i)`
+    /// for pairs `(x_i, y_i)` of prepared `PixelG2` and `PixelG1` elements,
+    /// in whichever order the engine expects them.
+    fn pixel_miller_loop(terms: &[(&PixelG2Prepared<Self>, &PixelG1Prepared<Self>)]) -> Gt<Self>;
+
+    /// The length of an encoded element of the target group.
+    const GT_LEN: usize;
+
+    /// Appends the encoding of an element of the target group to the buffer.
+    fn write_gt(x: &Gt<Self>, buf: &mut Vec<u8>);
+
+    /// Decodes an element of the target group.
+    /// It returns an error if the length is not `GT_LEN`,
+    /// or if the encoding is not canonical.
+    fn read_gt(bytes: &[u8]) -> Result<Gt<Self>, String>;
+}
+
+/// The target group of an engine.
+pub type Gt<E> = <<E as PixelEngine>::Engine as Engine>::Fqk;
+
+/// The affine form of a `PixelG1` element of an engine.
+pub type PixelG1Affine<E> = <<E as PixelEngine>::PixelG1 as CurveProjective>::Affine;
+/// The affine form of a `PixelG2` element of an engine.
+pub type PixelG2Affine<E> = <<E as PixelEngine>::PixelG2 as CurveProjective>::Affine;
+/// The prepared form of a `PixelG1` element of an engine.
+pub type PixelG1Prepared<E> = <PixelG1Affine<E> as CurveAffine>::Prepared;
+/// The prepared form of a `PixelG2` element of an engine.
+pub type PixelG2Prepared<E> = <PixelG2Affine<E> as CurveAffine>::Prepared;
+
+/// BLS12-381 uses the crate level `PixelG1` and `PixelG2`, i.e.,
+/// the group assignment is determined by feature `no_swap`.
+impl PixelEngine for Bls12 {
+    type Engine = Bls12;
+    type PixelG1 = crate::PixelG1;
+    type PixelG2 = crate::PixelG2;
+
+    // by default `PixelG2` is G1, so the pairs are in the engine's order
+    #[cfg(not(feature = "no_swap"))]
+    fn pixel_miller_loop(terms: &[(&PixelG2Prepared<Self>, &PixelG1Prepared<Self>)]) -> Fq12 {
+        Bls12::miller_loop(terms)
+    }
+
+    // with `no_swap`, `PixelG1` is G1, so the pairs are swapped
+    #[cfg(feature = "no_swap")]
+    fn pixel_miller_loop(terms: &[(&PixelG2Prepared<Self>, &PixelG1Prepared<Self>)]) -> Fq12 {
+        let terms: Vec<_> = terms.iter().map(|(x, y)| (*y, *x)).collect();
+        Bls12::miller_loop(terms.iter())
+    }
+
+    // 12 elements of Fq, 48 bytes each
+    const GT_LEN: usize = 576;
+
+    // the coefficients of Fq12 = Fq6[w] = (Fq2[v])[w] = ((Fq[u])[v])[w],
+    // from c0.c0.c0 to c1.c2.c1, each as a 48 bytes big-endian integer
+    fn write_gt(x: &Fq12, buf: &mut Vec<u8>) {
+        for fq6 in [x.c0, x.c1].iter() {
+            for fq2 in [fq6.c0, fq6.c1, fq6.c2].iter() {
+                for fq in [fq2.c0, fq2.c1].iter() {
+                    for limb in fq.into_repr().as_ref().iter().rev() {
+                        buf.extend_from_slice(&limb.to_be_bytes());
+                    }
+                }
+            }
+        }
+    }
+
+    fn read_gt(bytes: &[u8]) -> Result<Fq12, String> {
+        if bytes.len() != Self::GT_LEN {
+            return Err(ERR_SERIAL_LEN.to_owned());
+        }
+        let mut coeffs = [Fq::zero(); 12];
+        for (c, chunk) in coeffs.iter_mut().zip(bytes.chunks(48)) {
+            let mut repr = FqRepr::default();
+            for (limb, b) in repr.as_mut().iter_mut().rev().zip(chunk.chunks(8)) {
+                let mut limb_bytes = [0u8; 8];
+                limb_bytes.copy_from_slice(b);
+                *limb = u64::from_be_bytes(limb_bytes);
+            }
+            *c = match Fq::from_repr(repr) {
+                Ok(p) => p,
+                Err(_) => return Err(ERR_GT_ELEMENT.to_owned()),
+            };
+        }
+        let fq2 = |i: usize| Fq2 {
+            c0: coeffs[i],
+            c1: coeffs[i + 1],
+        };
+        Ok(Fq12 {
+            c0: Fq6 {
+                c0: fq2(0),
+                c1: fq2(2),
+                c2: fq2(4),
+            },
+            c1: Fq6 {
+                c0: fq2(6),
+                c1: fq2(8),
+                c2: fq2(10),
+            },
+        })
+    }
+}
@@ -0,0 +1,128 @@
+// This module tests the parameter generation pipeline through the
+// `PixelEngine` trait, over two engines: `Bls12`, and `SwappedBls12`,
+// a test engine with the other group assignment over BLS12-381.
+// `pairing-plus` has no other curve, so the second engine differs in its
+// groups, point sizes and pairing order rather than in its curve.
+
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::constants::{SHA512_IV, VALID_CIPHERSUITE};
+use crate::engine::{Gt, PixelG1Prepared, PixelG2Prepared};
+use crate::{
+    GenericPubParam, PixelEngine, PubParam, CONST_D, PP_LEN_COMPRESSED, PP_LEN_UNCOMPRESSED,
+};
+use pairing::bls12_381::{Bls12, Fq12};
+use pairing::{CurveAffine, CurveProjective, EncodedPoint, Engine};
+
+// BLS12-381 with `PixelG1` and `PixelG2` exchanged w.r.t. `Bls12`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct SwappedBls12;
+
+impl PixelEngine for SwappedBls12 {
+    type Engine = Bls12;
+    type PixelG1 = crate::PixelG2;
+    type PixelG2 = crate::PixelG1;
+
+    // the pairs are in the order of `Bls12` once swapped
+    fn pixel_miller_loop(terms: &[(&PixelG2Prepared<Self>, &PixelG1Prepared<Self>)]) -> Fq12 {
+        let terms: Vec<_> = terms.iter().map(|(x, y)| (*y, *x)).collect();
+        Bls12::pixel_miller_loop(&terms)
+    }
+
+    const GT_LEN: usize = <Bls12 as PixelEngine>::GT_LEN;
+
+    fn write_gt(x: &Fq12, buf: &mut Vec<u8>) {
+        Bls12::write_gt(x, buf)
+    }
+
+    fn read_gt(bytes: &[u8]) -> Result<Fq12, String> {
+        Bls12::read_gt(bytes)
+    }
+}
+
+// derive a parameter set for an engine, check it against the engine's
+// point sizes, and check that it survives the serialization
+fn check_engine<E: PixelEngine>() -> GenericPubParam<E> {
+    let seed = [0x42u8; 32];
+    let pp = GenericPubParam::<E>::init(&seed, VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp.depth(), CONST_D);
+    assert_eq!(pp.g2(), E::PixelG2::one());
+    assert!(!pp.h().is_zero());
+
+    // the same seed yields the same parameters
+    let pp2 = GenericPubParam::<E>::init(&seed, VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp, pp2);
+
+    // the lengths are given by the encoded point sizes
+    let g1_len = <<E::PixelG1 as CurveProjective>::Affine as CurveAffine>::Compressed::size();
+    let g2_len = <<E::PixelG2 as CurveProjective>::Affine as CurveAffine>::Compressed::size();
+    let len = 2 + g2_len + (CONST_D + 2) * g1_len;
+    assert_eq!(pp.size(true), len);
+    assert_eq!(pp.size(false), len * 2 - 2);
+
+    for &compressed in [true, false].iter() {
+        let bytes = pp.to_bytes(compressed).unwrap();
+        assert_eq!(bytes.len(), pp.size(compressed));
+        assert_eq!(
+            GenericPubParam::<E>::from_bytes(&bytes, compressed).unwrap(),
+            pp
+        );
+        // a truncated blob is rejected
+        assert!(GenericPubParam::<E>::from_bytes(&bytes[..len - 1], compressed).is_err());
+
+        let bytes = pp.to_bytes_extended(compressed).unwrap();
+        assert_eq!(bytes.len(), pp.size(compressed) + E::GT_LEN);
+        let pp_recover = GenericPubParam::<E>::from_bytes_extended(&bytes, compressed).unwrap();
+        assert_eq!(pp_recover, pp);
+        assert_eq!(pp_recover.gt_g2_h(), pp.gt_g2_h());
+    }
+    pp
+}
+
+// e(g2, h) computed by the engine, in its order of the groups
+fn pairing_g2_h<E: PixelEngine>(pp: &GenericPubParam<E>) -> Gt<E> {
+    let g2 = pp.g2_affine().prepare();
+    let h = pp.h_affine().prepare();
+    pp.prepared().multi_pairing(&[(&g2, &h)]).unwrap()
+}
+
+#[test]
+fn test_engine_bls12_381() {
+    let pp = check_engine::<Bls12>();
+    assert_eq!(pp.size(true), PP_LEN_COMPRESSED);
+    assert_eq!(pp.size(false), PP_LEN_UNCOMPRESSED);
+    assert_eq!(pairing_g2_h(&pp), pp.gt_g2_h());
+
+    // the default engine follows the default parameters
+    let pp = PubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp, PubParam::default());
+}
+
+#[test]
+fn test_engine_swapped_bls12_381() {
+    let pp = check_engine::<SwappedBls12>();
+    let pp_bls12 = check_engine::<Bls12>();
+
+    // the compressed lengths of G1 and G2 are 48 and 96 bytes,
+    // and the groups are exchanged w.r.t. `Bls12`
+    #[cfg(not(feature = "no_swap"))]
+    assert_eq!((pp.size(true), pp_bls12.size(true)), (1730, 3314));
+    #[cfg(feature = "no_swap")]
+    assert_eq!((pp.size(true), pp_bls12.size(true)), (3314, 1730));
+
+    // the pairing goes through the swapped Miller loop
+    #[cfg(not(feature = "no_swap"))]
+    let expected = Bls12::pairing(pp.h(), pp.g2());
+    #[cfg(feature = "no_swap")]
+    let expected = Bls12::pairing(pp.g2(), pp.h());
+    assert_eq!(pp.gt_g2_h(), expected);
+    assert_eq!(pairing_g2_h(&pp), expected);
+
+    // the blobs of the two engines do not decode as each other
+    let bytes = pp.to_bytes(true).unwrap();
+    assert!(PubParam::from_bytes(&bytes, true).is_err());
+    assert!(
+        GenericPubParam::<SwappedBls12>::from_bytes(&pp_bls12.to_bytes(true).unwrap(), true)
+            .is_err()
+    );
+}
@@ -0,0 +1,72 @@
+// This module tests the comparison and hashing of the public parameters.
+
+use crate::constants::VALID_CIPHERSUITE;
+use crate::{ConstantTimeEq, PubParam};
+use std::collections::{BTreeSet, HashMap};
+
+#[test]
+fn test_eq_hash_ord() {
+    let def_pp = PubParam::default();
+    let pp_other = PubParam::init(&[0x42; 32], VALID_CIPHERSUITE[0]).unwrap();
+    let pp_short = def_pp.truncate(4).unwrap();
+
+    // as map keys
+    let mut map = HashMap::new();
+    map.insert(def_pp.clone(), "default");
+    map.insert(pp_other.clone(), "other");
+    map.insert(PubParam::init_without_seed(), "default again");
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&def_pp], "default again");
+
+    // the order is deterministic, and consistent with the encodings
+    let set: BTreeSet<PubParam> = vec![
+        pp_other.clone(),
+        def_pp.clone(),
+        pp_short.clone(),
+        def_pp.clone(),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 3);
+    let sorted: Vec<PubParam> = set.into_iter().collect();
+    for w in sorted.windows(2) {
+        assert!(w[0] < w[1]);
+        assert!(w[0].to_bytes(true).unwrap() < w[1].to_bytes(true).unwrap());
+    }
+    // a smaller depth comes first
+    assert!(pp_short < def_pp);
+    assert_eq!(def_pp.cmp(&def_pp.clone()), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn test_ct_eq() {
+    let def_pp = PubParam::default();
+    let pp_other = PubParam::init(&[0x42; 32], VALID_CIPHERSUITE[0]).unwrap();
+    assert!(bool::from(def_pp.ct_eq(&PubParam::init_without_seed())));
+    assert!(!bool::from(def_pp.ct_eq(&pp_other)));
+    assert!(!bool::from(def_pp.ct_eq(&def_pp.truncate(4).unwrap())));
+    assert!(!bool::from(def_pp.truncate(4).unwrap().ct_eq(&def_pp)));
+
+    // `==` agrees with `ct_eq`
+    assert!(def_pp == PubParam::init_without_seed());
+    assert!(def_pp != pp_other);
+    assert!(def_pp != def_pp.truncate(4).unwrap());
+}
+
+#[test]
+fn test_encoding_cached() {
+    let def_pp = PubParam::default();
+    let bytes = def_pp.to_bytes(true).unwrap();
+
+    // the comparisons encode the parameters once, and reuse the encoding
+    assert!(def_pp == def_pp.clone());
+    let cached = def_pp.encoded().as_ptr();
+    assert!(def_pp == PubParam::init_without_seed());
+    assert_eq!(def_pp.encoded().as_ptr(), cached);
+    assert_eq!(def_pp.encoded(), &bytes[..]);
+
+    // a clone keeps a copy of the cached encoding
+    let pp_clone = def_pp.clone();
+    assert_eq!(pp_clone.encoded(), &bytes[..]);
+    assert_eq!(pp_clone.fingerprint(), def_pp.fingerprint());
+}
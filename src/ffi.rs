@@ -0,0 +1,201 @@
+// This module exposes the public parameters through a C ABI.
+// The header `include/pixel_param.h` is generated by cbindgen.
+//
+// None of the functions panics across the boundary:
+// every failure is reported via an error code, and
+// a `PubParam` is handed over as an opaque pointer that
+// must be released with `pixel_param_free`.
+
+use crate::constants::{ERR_CIPHERSUITE, ERR_SEED_TOO_SHORT};
+use crate::PubParam;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::{ptr, slice};
+
+/// The call succeeded.
+pub const PIXEL_PARAM_OK: i32 = 0;
+/// A required pointer argument is null.
+pub const PIXEL_PARAM_ERR_NULL_POINTER: i32 = -1;
+/// The seed is shorter than 32 bytes.
+pub const PIXEL_PARAM_ERR_SEED_TOO_SHORT: i32 = -2;
+/// The ciphersuite id is not supported.
+pub const PIXEL_PARAM_ERR_CIPHERSUITE: i32 = -3;
+/// The output buffer is too small; the required length is written to `out_len`.
+pub const PIXEL_PARAM_ERR_BUFFER_TOO_SMALL: i32 = -4;
+/// The blob is not a valid public parameter.
+pub const PIXEL_PARAM_ERR_DESERIALIZE: i32 = -5;
+/// An unexpected internal error occurred.
+pub const PIXEL_PARAM_ERR_INTERNAL: i32 = -6;
+
+/// Run `f`, and convert a panic into `PIXEL_PARAM_ERR_INTERNAL`.
+fn guard<F: FnOnce() -> i32>(f: F) -> i32 {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(code) => code,
+        Err(_) => PIXEL_PARAM_ERR_INTERNAL,
+    }
+}
+
+/// Hand a public parameter over to the caller.
+unsafe fn export(pp: PubParam, out: *mut *mut PubParam) -> i32 {
+    *out = Box::into_raw(Box::new(pp));
+    PIXEL_PARAM_OK
+}
+
+/// Writes the default public parameter into `out`.
+/// The parameter must be released with `pixel_param_free`.
+///
+/// # Safety
+/// `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn pixel_param_default(out: *mut *mut PubParam) -> i32 {
+    if out.is_null() {
+        return PIXEL_PARAM_ERR_NULL_POINTER;
+    }
+    guard(|| export(PubParam::default(), out))
+}
+
+/// Generates a public parameter from `seed_len` bytes of `seed` and
+/// a ciphersuite id, and writes it into `out`.
+/// The parameter must be released with `pixel_param_free`.
+///
+/// # Safety
+/// `seed` must point to `seed_len` readable bytes and `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn pixel_param_init(
+    seed: *const u8,
+    seed_len: usize,
+    csid: u8,
+    out: *mut *mut PubParam,
+) -> i32 {
+    if seed.is_null() || out.is_null() {
+        return PIXEL_PARAM_ERR_NULL_POINTER;
+    }
+    guard(|| {
+        let seed = slice::from_raw_parts(seed, seed_len);
+        match PubParam::init(seed, csid) {
+            Ok(pp) => export(pp, out),
+            Err(e) => {
+                if e == ERR_SEED_TOO_SHORT {
+                    PIXEL_PARAM_ERR_SEED_TOO_SHORT
+                } else if e == ERR_CIPHERSUITE {
+                    PIXEL_PARAM_ERR_CIPHERSUITE
+                } else {
+                    PIXEL_PARAM_ERR_INTERNAL
+                }
+            }
+        }
+    })
+}
+
+/// Serializes the public parameter into `out`, which has `*out_len` bytes.
+/// On success, `*out_len` is set to the number of bytes written.
+/// If `out` is null or too small, `*out_len` is set to the required
+/// length and `PIXEL_PARAM_ERR_BUFFER_TOO_SMALL` is returned.
+///
+/// # Safety
+/// `pp` must be a parameter obtained from this library, `out_len` a valid
+/// pointer, and `out` either null or pointing to `*out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn pixel_param_serialize(
+    pp: *const PubParam,
+    compressed: bool,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    if pp.is_null() || out_len.is_null() {
+        return PIXEL_PARAM_ERR_NULL_POINTER;
+    }
+    guard(|| {
+        let pp = &*pp;
+        let len = pp.size(compressed);
+        if out.is_null() || *out_len < len {
+            *out_len = len;
+            return PIXEL_PARAM_ERR_BUFFER_TOO_SMALL;
+        }
+        match pp.to_bytes(compressed) {
+            Ok(buf) => {
+                ptr::copy_nonoverlapping(buf.as_ptr(), out, buf.len());
+                *out_len = buf.len();
+                PIXEL_PARAM_OK
+            }
+            Err(_) => PIXEL_PARAM_ERR_CIPHERSUITE,
+        }
+    })
+}
+
+/// Deserializes `len` bytes of `buf` into a public parameter,
+/// and writes it into `out`.
+/// The parameter must be released with `pixel_param_free`.
+///
+/// # Safety
+/// `buf` must point to `len` readable bytes and `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn pixel_param_deserialize(
+    buf: *const u8,
+    len: usize,
+    compressed: bool,
+    out: *mut *mut PubParam,
+) -> i32 {
+    if buf.is_null() || out.is_null() {
+        return PIXEL_PARAM_ERR_NULL_POINTER;
+    }
+    guard(|| {
+        let buf = slice::from_raw_parts(buf, len);
+        match PubParam::from_bytes(buf, compressed) {
+            Ok(pp) => export(pp, out),
+            Err(_) => PIXEL_PARAM_ERR_DESERIALIZE,
+        }
+    })
+}
+
+/// Returns the depth of the public parameter, or 0 if `pp` is null.
+///
+/// # Safety
+/// `pp` must be null or a parameter obtained from this library.
+#[no_mangle]
+pub unsafe extern "C" fn pixel_param_depth(pp: *const PubParam) -> usize {
+    if pp.is_null() {
+        return 0;
+    }
+    (*pp).depth()
+}
+
+/// Returns the ciphersuite id of the public parameter, or 0xFF if `pp` is null.
+///
+/// # Safety
+/// `pp` must be null or a parameter obtained from this library.
+#[no_mangle]
+pub unsafe extern "C" fn pixel_param_ciphersuite(pp: *const PubParam) -> u8 {
+    if pp.is_null() {
+        return 0xFF;
+    }
+    (*pp).ciphersuite()
+}
+
+/// Writes the 32 bytes fingerprint of the public parameter into `out`.
+///
+/// # Safety
+/// `pp` must be a parameter obtained from this library and
+/// `out` must point to 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn pixel_param_fingerprint(pp: *const PubParam, out: *mut u8) -> i32 {
+    if pp.is_null() || out.is_null() {
+        return PIXEL_PARAM_ERR_NULL_POINTER;
+    }
+    guard(|| {
+        let fp = (*pp).fingerprint();
+        ptr::copy_nonoverlapping(fp.as_ptr(), out, fp.len());
+        PIXEL_PARAM_OK
+    })
+}
+
+/// Releases a public parameter. Passing null is a no-op.
+///
+/// # Safety
+/// `pp` must be null or a parameter obtained from this library,
+/// and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn pixel_param_free(pp: *mut PubParam) {
+    if !pp.is_null() {
+        drop(Box::from_raw(pp));
+    }
+}
@@ -0,0 +1,138 @@
+// This module tests the C ABI, both from rust and
+// from the C program `tests/c/ffi_test.c`.
+
+use crate::ffi::*;
+use crate::{PubParam, PP_LEN_COMPRESSED, PP_LEN_UNCOMPRESSED};
+use std::path::PathBuf;
+use std::process::Command;
+use std::ptr;
+
+#[test]
+fn test_ffi_roundtrip() {
+    unsafe {
+        let mut pp: *mut PubParam = ptr::null_mut();
+        assert_eq!(pixel_param_default(&mut pp), PIXEL_PARAM_OK);
+        assert!(!pp.is_null());
+
+        // query the length first
+        let mut len = 0;
+        assert_eq!(
+            pixel_param_serialize(pp, true, ptr::null_mut(), &mut len),
+            PIXEL_PARAM_ERR_BUFFER_TOO_SMALL
+        );
+        assert_eq!(len, PP_LEN_COMPRESSED);
+
+        let mut buf = vec![0u8; len];
+        assert_eq!(
+            pixel_param_serialize(pp, true, buf.as_mut_ptr(), &mut len),
+            PIXEL_PARAM_OK
+        );
+        let mut recovered: *mut PubParam = ptr::null_mut();
+        assert_eq!(
+            pixel_param_deserialize(buf.as_ptr(), len, true, &mut recovered),
+            PIXEL_PARAM_OK
+        );
+        assert_eq!(*pp, *recovered);
+        assert_eq!(*pp, PubParam::default());
+
+        pixel_param_free(pp);
+        pixel_param_free(recovered);
+    }
+}
+
+#[test]
+fn test_ffi_errors() {
+    unsafe {
+        let mut pp: *mut PubParam = ptr::null_mut();
+        let seed = [0u8; 32];
+        assert_eq!(
+            pixel_param_init(seed.as_ptr(), 31, 0, &mut pp),
+            PIXEL_PARAM_ERR_SEED_TOO_SHORT
+        );
+        assert_eq!(
+            pixel_param_init(seed.as_ptr(), 32, 0xFF, &mut pp),
+            PIXEL_PARAM_ERR_CIPHERSUITE
+        );
+        assert_eq!(
+            pixel_param_init(ptr::null(), 32, 0, &mut pp),
+            PIXEL_PARAM_ERR_NULL_POINTER
+        );
+        assert_eq!(
+            pixel_param_deserialize(seed.as_ptr(), 32, true, &mut pp),
+            PIXEL_PARAM_ERR_DESERIALIZE
+        );
+        assert!(pp.is_null());
+        assert_eq!(pixel_param_depth(ptr::null()), 0);
+        pixel_param_free(ptr::null_mut());
+    }
+}
+
+// the header generated by build.rs must match the checked in copy
+#[test]
+fn test_ffi_header() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let generated =
+        std::fs::read_to_string(PathBuf::from(env!("OUT_DIR")).join("pixel_param.h")).unwrap();
+    let checked_in = std::fs::read_to_string(manifest_dir.join("include/pixel_param.h")).unwrap();
+    assert_eq!(
+        generated, checked_in,
+        "include/pixel_param.h is out of date, copy it from OUT_DIR"
+    );
+}
+
+// build the static library with `cargo rustc --crate-type staticlib`,
+// compile `tests/c/ffi_test.c` against it and run it;
+// the compiler can be set via the `CC` environment variable
+#[test]
+fn test_ffi_c_program() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // the test binary lives in `target/<profile>/deps`; the static library
+    // is built in `target/ffi`, so that it does not contend with this build
+    let exe = std::env::current_exe().unwrap();
+    let target_dir = exe
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("ffi");
+    let out = target_dir.join("pixel_param_ffi_test");
+
+    // the library must have the same group assignment as this build
+    let mut features = "ffi".to_owned();
+    if cfg!(feature = "no_swap") {
+        features.push_str(",no_swap");
+    }
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let status = Command::new(cargo)
+        .args(&["rustc", "--lib", "--crate-type", "staticlib", "--features"])
+        .arg(&features)
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .expect("Error invoking cargo");
+    assert!(status.success(), "Error building the static library");
+
+    let cc = std::env::var("CC").unwrap_or_else(|_| "cc".to_owned());
+    let status = Command::new(cc)
+        .arg(manifest_dir.join("tests/c/ffi_test.c"))
+        .arg("-I")
+        .arg(env!("OUT_DIR"))
+        .arg("-o")
+        .arg(&out)
+        .arg(target_dir.join("debug").join("libpixel_param.a"))
+        .args(&["-lpthread", "-ldl", "-lm"])
+        .status()
+        .expect("Error invoking the C compiler");
+    assert!(status.success(), "Error compiling the C test program");
+
+    let status = Command::new(&out)
+        .arg(PP_LEN_COMPRESSED.to_string())
+        .arg(PP_LEN_UNCOMPRESSED.to_string())
+        .status()
+        .expect("Error running the C test program");
+    assert_eq!(status.code(), Some(0), "C test failed at the returned line");
+}
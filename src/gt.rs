@@ -0,0 +1,124 @@
+// This module caches the pairing `e(g2, h)`, which the Pixel verification
+// equation reuses for every signature, and defines the extended parameter
+// file that carries it:
+//
+// `| ciphersuite id | depth | g2 | h | hlist | e(g2, h) |`
+//
+// i.e., the blob of `to_bytes` followed by the encoding of `e(g2, h)`,
+// see `PixelEngine::write_gt`. `from_bytes_extended` checks the pairing
+// value against the parameters, which costs the pairing itself;
+// `from_bytes_extended_unchecked` trusts it, and is the one that saves
+// the pairing, for files that come from a trusted source.
+//
+// `batch_verify_gt` checks a batch of pairing equations whose right hand
+// side is `e(g2, h)`, with one final exponentiation for the whole batch.
+
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::boxed::Box;
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::constants::{ERR_GT_MISMATCH, ERR_SERIAL_LEN};
+use crate::engine::{Gt, PixelG1Prepared, PixelG2Prepared};
+use crate::{GenericPubParam, PixelEngine};
+use ff::Field;
+use pairing::{CurveAffine, Engine};
+use rand_core::RngCore;
+
+impl<E: PixelEngine> GenericPubParam<E> {
+    /// Returns `e(g2, h)` in the target group.
+    /// It is computed on the first call and cached afterwards.
+    pub fn gt_g2_h(&self) -> Gt<E> {
+        *self
+            .gt_g2_h
+            .get_or_init(|| Box::new(self.compute_gt_g2_h()))
+    }
+
+    fn compute_gt_g2_h(&self) -> Gt<E> {
+        let ml = E::pixel_miller_loop(&[(&self.g2_affine.prepare(), &self.h_affine.prepare())]);
+        // the Miller loop is never zero, so the final exponentiation succeeds
+        <E::Engine as Engine>::final_exponentiation(&ml).unwrap()
+    }
+
+    /// Converts the public parameter into an extended blob:
+    /// `|ciphersuite id| depth | g2 | h | hlist | e(g2, h) |`.
+    /// Returns an error under the same conditions as `to_bytes`.
+    pub fn to_bytes_extended(&self, compressed: bool) -> Result<Vec<u8>, String> {
+        let mut buf = self.to_bytes(compressed)?;
+        E::write_gt(&self.gt_g2_h(), &mut buf);
+        Ok(buf)
+    }
+
+    /// Converts an extended blob into a public parameter;
+    /// `e(g2, h)` is checked against the parameters, and cached.
+    /// The check computes the pairing; use `from_bytes_extended_unchecked`
+    /// to skip it for a trusted file.
+    ///
+    /// Returns an error under the same conditions as `from_bytes`,
+    /// if the pairing value is malformed,
+    /// or if it does not match the parameters.
+    pub fn from_bytes_extended(bytes: &[u8], compressed: bool) -> Result<Self, String> {
+        let (pp, gt) = Self::split_extended(bytes, compressed)?;
+        if gt != pp.gt_g2_h() {
+            return Err(ERR_GT_MISMATCH.to_owned());
+        }
+        Ok(pp)
+    }
+
+    /// Converts an extended blob into a public parameter, and caches
+    /// the stored `e(g2, h)` **without checking it**, so that no pairing
+    /// is computed.
+    ///
+    /// Only use it on a file from a trusted source, e.g., one written by
+    /// `to_bytes_extended` and checked once with `from_bytes_extended`:
+    /// a wrong pairing value makes every equation that uses it wrong.
+    ///
+    /// Returns an error under the same conditions as `from_bytes`,
+    /// or if the pairing value is malformed.
+    pub fn from_bytes_extended_unchecked(bytes: &[u8], compressed: bool) -> Result<Self, String> {
+        let (pp, gt) = Self::split_extended(bytes, compressed)?;
+        // the cache of a freshly decoded parameter set is empty
+        let _ = pp.gt_g2_h.set(Box::new(gt));
+        Ok(pp)
+    }
+
+    // decodes the parameters and the pairing value of an extended blob
+    fn split_extended(bytes: &[u8], compressed: bool) -> Result<(Self, Gt<E>), String> {
+        if bytes.len() < E::GT_LEN {
+            return Err(ERR_SERIAL_LEN.to_owned());
+        }
+        let (pp_bytes, gt_bytes) = bytes.split_at(bytes.len() - E::GT_LEN);
+        let pp = Self::from_bytes(pp_bytes, compressed)?;
+        let gt = E::read_gt(gt_bytes)?;
+        Ok((pp, gt))
+    }
+
+    /// Checks a batch of equations `prod_i e(x_i, y_i) = e(g2, h)`, where each
+    /// equation is given by its pairs of prepared `PixelG2` and `PixelG1` elements.
+    ///
+    /// The equations are combined with random 64 bits exponents `r_j` from `rng`,
+    /// i.e., `FE(prod_j ML_j^{r_j}) = e(g2, h)^{sum_j r_j}`, so that the batch
+    /// computes no Miller loop for `e(g2, h)`, which is cached, and a single
+    /// final exponentiation. A batch with an invalid equation passes
+    /// with probability about `2^-64`.
+    ///
+    /// Returns true on an empty batch.
+    pub fn batch_verify_gt<R: RngCore>(
+        &self,
+        equations: &[&[(&PixelG2Prepared<E>, &PixelG1Prepared<E>)]],
+        rng: &mut R,
+    ) -> bool {
+        let gt = self.gt_g2_h();
+        let mut lhs = Gt::<E>::one();
+        let mut rhs = Gt::<E>::one();
+        for terms in equations {
+            // a zero exponent would drop the equation from the batch
+            let r = [rng.next_u64().max(1)];
+            lhs.mul_assign(&E::pixel_miller_loop(terms).pow(r));
+            rhs.mul_assign(&gt.pow(r));
+        }
+        match <E::Engine as Engine>::final_exponentiation(&lhs) {
+            Some(lhs) => lhs == rhs,
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// This module tests the cached pairing value and the extended parameter file.
+
+use crate::constants::VALID_CIPHERSUITE;
+use crate::{PixelEngine, PixelG1, PixelG2, PubParam};
+use pairing::bls12_381::{Bls12, Fq12};
+use pairing::{CurveAffine, CurveProjective, Engine};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// a fresh e(g2, h) in the engine's order of the groups
+fn fresh_gt_g2_h(pp: &PubParam) -> Fq12 {
+    #[cfg(not(feature = "no_swap"))]
+    {
+        Bls12::pairing(pp.g2(), pp.h())
+    }
+    #[cfg(feature = "no_swap")]
+    {
+        Bls12::pairing(pp.h(), pp.g2())
+    }
+}
+
+#[test]
+fn test_gt_g2_h() {
+    let pp = PubParam::default();
+    let gt = pp.gt_g2_h();
+    assert_eq!(gt, fresh_gt_g2_h(&pp));
+    // cached, and carried over by clone
+    assert_eq!(pp.gt_g2_h(), gt);
+    assert_eq!(pp.clone().gt_g2_h(), gt);
+
+    // distinct parameters give distinct values
+    let pp_other = PubParam::init(&[0x42; 32], VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp_other.gt_g2_h(), fresh_gt_g2_h(&pp_other));
+    assert_ne!(pp_other.gt_g2_h(), gt);
+}
+
+#[test]
+fn test_gt_extended_file() {
+    let pp = PubParam::default();
+    for &compressed in [true, false].iter() {
+        let bytes = pp.to_bytes_extended(compressed).unwrap();
+        assert_eq!(
+            bytes.len(),
+            pp.size(compressed) + <Bls12 as PixelEngine>::GT_LEN
+        );
+        assert_eq!(
+            bytes[..pp.size(compressed)],
+            pp.to_bytes(compressed).unwrap()[..]
+        );
+
+        let pp_recover = PubParam::from_bytes_extended(&bytes, compressed).unwrap();
+        assert_eq!(pp, pp_recover);
+        assert_eq!(pp_recover.gt_g2_h(), fresh_gt_g2_h(&pp));
+
+        // the pairing value must match the parameters
+        let mut bad = bytes.clone();
+        let len = bad.len();
+        bad[len - 1] ^= 1;
+        assert!(PubParam::from_bytes_extended(&bad, compressed).is_err());
+        // and be canonical
+        let mut bad = bytes.clone();
+        bad[len - 48] = 0xFF;
+        assert!(PubParam::from_bytes_extended(&bad, compressed).is_err());
+        // a plain blob is not an extended one
+        let plain = pp.to_bytes(compressed).unwrap();
+        assert!(PubParam::from_bytes_extended(&plain, compressed).is_err());
+    }
+
+    // the encoding round trips
+    let gt = pp.gt_g2_h();
+    let mut buf = vec![];
+    Bls12::write_gt(&gt, &mut buf);
+    assert_eq!(Bls12::read_gt(&buf).unwrap(), gt);
+}
+
+#[test]
+fn test_gt_extended_file_unchecked() {
+    let pp = PubParam::default();
+    for &compressed in [true, false].iter() {
+        let bytes = pp.to_bytes_extended(compressed).unwrap();
+        let pp_recover = PubParam::from_bytes_extended_unchecked(&bytes, compressed).unwrap();
+        assert_eq!(pp, pp_recover);
+        assert_eq!(pp_recover.gt_g2_h(), fresh_gt_g2_h(&pp));
+
+        // the stored value is trusted as is, as long as it is well formed
+        let mut bad = bytes.clone();
+        let len = bad.len();
+        bad[len - 1] ^= 1;
+        let pp_bad = PubParam::from_bytes_extended_unchecked(&bad, compressed).unwrap();
+        assert_eq!(pp_bad, pp);
+        assert_ne!(pp_bad.gt_g2_h(), fresh_gt_g2_h(&pp));
+        assert!(PubParam::from_bytes_extended(&bad, compressed).is_err());
+        let mut bad = bytes.clone();
+        bad[len - 48] = 0xFF;
+        assert!(PubParam::from_bytes_extended_unchecked(&bad, compressed).is_err());
+        let plain = pp.to_bytes(compressed).unwrap();
+        assert!(PubParam::from_bytes_extended_unchecked(&plain, compressed).is_err());
+    }
+}
+
+#[test]
+fn test_batch_verify_gt() {
+    let pp = PubParam::default();
+    let mut rng = StdRng::from_seed([0x42; 32]);
+    let prepare_g2 = |x: PixelG2| x.into_affine().prepare();
+    let prepare_g1 = |y: PixelG1| y.into_affine().prepare();
+
+    let g2 = prepare_g2(pp.g2());
+    let h = prepare_g1(pp.h());
+    let mut g2_double = pp.g2();
+    g2_double.double();
+    let g2_double = prepare_g2(g2_double);
+    let mut g2_neg = pp.g2();
+    g2_neg.negate();
+    let g2_neg = prepare_g2(g2_neg);
+    let mut h_double = pp.h();
+    h_double.double();
+    let h_double = prepare_g1(h_double);
+
+    // e(g2, h), e(2 g2, h) e(-g2, h) and e(g2, 2 h) e(-g2, h) are e(g2, h)
+    let valid_1 = [(&g2, &h)];
+    let valid_2 = [(&g2_double, &h), (&g2_neg, &h)];
+    let valid_3 = [(&g2, &h_double), (&g2_neg, &h)];
+    // e(2 g2, h) is not
+    let invalid_1 = [(&g2_double, &h)];
+
+    assert!(pp.batch_verify_gt(&[], &mut rng));
+    assert!(pp.batch_verify_gt(&[&valid_1[..]], &mut rng));
+    assert!(pp.batch_verify_gt(&[&valid_1[..], &valid_2[..], &valid_3[..]], &mut rng));
+    assert!(!pp.batch_verify_gt(&[&invalid_1[..]], &mut rng));
+    assert!(!pp.batch_verify_gt(&[&valid_1[..], &invalid_1[..], &valid_2[..]], &mut rng));
+
+    // with the pairing value of a trusted file
+    let bytes = pp.to_bytes_extended(true).unwrap();
+    let pp = PubParam::from_bytes_extended_unchecked(&bytes, true).unwrap();
+    assert!(pp.batch_verify_gt(&[&valid_1[..], &valid_2[..]], &mut rng));
+    assert!(!pp.batch_verify_gt(&[&valid_2[..], &invalid_1[..]], &mut rng));
+}
@@ -0,0 +1,138 @@
+// This module defines the key derivation functions that extract and expand
+// the seed during parameter generation. The hash function of the HKDF is
+// chosen by the ciphersuite id:
+//
+// | ciphersuite id | KDF           |
+// |----------------|---------------|
+// | 0x00, 0x01     | HKDF-SHA512   |
+// | 0x02, 0x03     | HKDF-SHA256   |
+// | 0x04, 0x05     | HKDF-BLAKE2b  |
+// | 0x06, 0x07     | HKDF-SHA3-512 |
+//
+// With feature `no_swap` the same table applies with `NO_SWAP_FLAG` set.
+
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::constants::{ERR_CIPHERSUITE, ERR_HKDF, NO_SWAP_FLAG, VALID_CIPHERSUITE};
+use blake2::Blake2b;
+use core::marker::PhantomData;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use sha2::{Sha256, Sha512};
+use sha3::Sha3_512;
+
+/// The interface of a key derivation function, i.e.,
+/// HKDF-Extract and HKDF-Expand over some hash function.
+pub trait ParamKdf: Sized {
+    /// `HKDF-Extract(salt, seed) -> secret`
+    fn extract(salt: &[u8], seed: &[u8]) -> Self;
+
+    /// `HKDF-Expand(secret, info, output.len()) -> output`
+    fn expand(&self, info: &[u8], output: &mut [u8]) -> Result<(), String>;
+}
+
+// the PRK; with feature `zeroize` it is wiped when dropped,
+// whichever path drops it
+#[cfg(feature = "zeroize")]
+type Prk = zeroize::Zeroizing<Vec<u8>>;
+#[cfg(not(feature = "zeroize"))]
+type Prk = Vec<u8>;
+
+/// HKDF over the hash function `H`.
+/// The PRK `HMAC(salt, seed)` is computed and owned by this struct
+/// rather than by `hkdf::Hkdf`, so that it can be wiped:
+/// with feature `zeroize` it is zeroized on drop.
+/// The `Hkdf` for HKDF-Expand is rebuilt from the PRK for each expansion.
+pub struct ParamHkdf<H> {
+    prk: Prk,
+    hash: PhantomData<H>,
+}
+
+#[cfg(test)]
+impl<H> ParamHkdf<H> {
+    /// Returns the PRK.
+    pub(crate) fn prk(&self) -> &[u8] {
+        &self.prk[..]
+    }
+}
+
+/// The PRK is not printed.
+impl<H> core::fmt::Debug for ParamHkdf<H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "ParamHkdf {{ prk len: {} }}", self.prk.len())
+    }
+}
+
+macro_rules! impl_param_kdf {
+    ($hash:ty) => {
+        impl ParamKdf for ParamHkdf<$hash> {
+            // HKDF-Extract(salt, seed) = HMAC(salt, seed)
+            fn extract(salt: &[u8], seed: &[u8]) -> Self {
+                let mut mac =
+                    Hmac::<$hash>::new_varkey(salt).expect("HMAC takes keys of any length");
+                mac.input(seed);
+                #[cfg(feature = "zeroize")]
+                let prk = {
+                    use zeroize::Zeroize;
+                    let mut code = mac.result().code();
+                    let prk = Prk::new(code.to_vec());
+                    code.as_mut_slice().zeroize();
+                    prk
+                };
+                #[cfg(not(feature = "zeroize"))]
+                let prk = mac.result().code().to_vec();
+                ParamHkdf {
+                    prk,
+                    hash: PhantomData,
+                }
+            }
+
+            fn expand(&self, info: &[u8], output: &mut [u8]) -> Result<(), String> {
+                let hk = match Hkdf::<$hash>::from_prk(&self.prk[..]) {
+                    Ok(hk) => hk,
+                    Err(_) => return Err(ERR_HKDF.to_owned()),
+                };
+                match hk.expand(info, output) {
+                    Ok(()) => Ok(()),
+                    Err(_) => Err(ERR_HKDF.to_owned()),
+                }
+            }
+        }
+    };
+}
+
+impl_param_kdf!(Sha512);
+impl_param_kdf!(Sha256);
+impl_param_kdf!(Blake2b);
+impl_param_kdf!(Sha3_512);
+
+/// The hash functions that the KDF can be instantiated with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KdfHash {
+    /// HKDF-SHA512, the default.
+    Sha512,
+    /// HKDF-SHA256.
+    Sha256,
+    /// HKDF-BLAKE2b.
+    Blake2b,
+    /// HKDF-SHA3-512.
+    Sha3_512,
+}
+
+impl KdfHash {
+    /// Returns the hash function selected by a ciphersuite id,
+    /// or an error if the ciphersuite is not supported.
+    pub fn from_ciphersuite(ciphersuite: u8) -> Result<Self, String> {
+        if !VALID_CIPHERSUITE.contains(&ciphersuite) {
+            return Err(ERR_CIPHERSUITE.to_owned());
+        }
+        match (ciphersuite & !NO_SWAP_FLAG) >> 1 {
+            0 => Ok(KdfHash::Sha512),
+            1 => Ok(KdfHash::Sha256),
+            2 => Ok(KdfHash::Blake2b),
+            3 => Ok(KdfHash::Sha3_512),
+            _ => Err(ERR_CIPHERSUITE.to_owned()),
+        }
+    }
+}
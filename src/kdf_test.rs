@@ -0,0 +1,95 @@
+// This module implements known answer tests for the KDFs.
+// The expected outputs are computed with python's `hmac` and `hashlib`.
+
+use crate::constants::{DOM_SEP_PARAM_GEN, SHA512_IV, VALID_CIPHERSUITE};
+use crate::kdf::{KdfHash, ParamHkdf, ParamKdf};
+use crate::PubParam;
+use blake2::Blake2b;
+use sha2::{Sha256, Sha512};
+use sha3::Sha3_512;
+
+// expand `H2G_h` and `H2G_h | I2OSP(32, 1)` from the default seed,
+// i.e., the inputs to hash_to_group for `h` and `h_32`
+fn kat<K: ParamKdf>(expect_h: &str, expect_h32: &str) {
+    let hk = K::extract(DOM_SEP_PARAM_GEN.as_ref(), SHA512_IV.as_ref());
+    let mut output = [0u8; 32];
+    hk.expand(b"H2G_h", &mut output).unwrap();
+    assert_eq!(to_hex(&output), expect_h);
+    hk.expand(b"H2G_h\x20", &mut output).unwrap();
+    assert_eq!(to_hex(&output), expect_h32);
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn test_kdf_sha512() {
+    kat::<ParamHkdf<Sha512>>(
+        "6cd8ae42df69a0938e13e7b8c5c8326f2dc0eca1a6d1ef844bc6473182c57c71",
+        "1cc2858dbc8e8453917746ab6c819bf6e5577cade957633390e40314e8caacc7",
+    );
+}
+
+#[test]
+fn test_kdf_sha256() {
+    kat::<ParamHkdf<Sha256>>(
+        "579c375dc1432826fcef504b30b88559387ed853c6386eb2738375addb40cf7f",
+        "e010b357a6559f43ffc43bc0e1b2bff09704296591d1715f5fa2bc106d22ce50",
+    );
+
+    // RFC 5869, test case 1
+    let salt: Vec<u8> = (0x00..=0x0c).collect();
+    let info: Vec<u8> = (0xf0..=0xf9).collect();
+    let hk = <ParamHkdf<Sha256> as ParamKdf>::extract(&salt, &[0x0b; 22]);
+    assert_eq!(
+        to_hex(hk.prk()),
+        "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"
+    );
+    let mut output = [0u8; 42];
+    ParamKdf::expand(&hk, &info, &mut output).unwrap();
+    assert_eq!(
+        to_hex(&output),
+        "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+    );
+}
+
+#[test]
+fn test_kdf_blake2b() {
+    kat::<ParamHkdf<Blake2b>>(
+        "05d107446aaf216bae407a1cc536e25877f9b5dadea1477c6f59bb1a97564094",
+        "8e9829fe9ee364088b5cd43f99203e5da89b33c87ea430a42ab34d6315000832",
+    );
+}
+
+#[test]
+fn test_kdf_sha3_512() {
+    kat::<ParamHkdf<Sha3_512>>(
+        "0d1cbe1298c70801f461daf8f0c5e3add6523e7b9fa50400474b6a4a5c020a92",
+        "805683de965bb253e4ef59602e6be6c22fa341e4b79c5ff66caaf7b6b812dd7c",
+    );
+}
+
+#[test]
+fn test_kdf_ciphersuite() {
+    let expect = [
+        KdfHash::Sha512,
+        KdfHash::Sha512,
+        KdfHash::Sha256,
+        KdfHash::Sha256,
+        KdfHash::Blake2b,
+        KdfHash::Blake2b,
+        KdfHash::Sha3_512,
+        KdfHash::Sha3_512,
+    ];
+    for (csid, hash) in VALID_CIPHERSUITE.iter().zip(expect.iter()) {
+        assert_eq!(KdfHash::from_ciphersuite(*csid).unwrap(), *hash);
+    }
+    assert!(KdfHash::from_ciphersuite(0xFF).is_err());
+
+    // different KDFs yield different parameters from the same seed
+    let pp_sha512 = PubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[0]).unwrap();
+    let pp_sha256 = PubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[2]).unwrap();
+    assert_eq!(pp_sha512, PubParam::default());
+    assert_ne!(pp_sha512.h(), pp_sha256.h());
+}
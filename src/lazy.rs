@@ -0,0 +1,147 @@
+// This module derives the elements of hlist on demand.
+// `LazyPubParam` keeps the extracted secret `m = HKDF-Extract(dst, seed)`,
+// and derives `h_i = hash_to_group(HKDF-Expand(m, info_i, 32), ciphersuite)`
+// on the first access, with the same info labels as `init`,
+// so that a verifier only pays for the elements on the path it needs.
+// Note that the secret is kept in memory for the lifetime of the struct;
+// with feature `zeroize` it is wiped on drop.
+
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::boxed::Box;
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::config::ParamGenConfig;
+use crate::constants::{CONST_D, ERR_HLIST_INDEX};
+use crate::kdf::{KdfHash, ParamHkdf, ParamKdf};
+use crate::{check_inputs, GenericPubParam, PixelEngine};
+use blake2::Blake2b;
+use once_cell::race::OnceBox;
+use pairing::bls12_381::Bls12;
+use pairing::CurveProjective;
+use sha2::{Sha256, Sha512};
+use sha3::Sha3_512;
+
+// the extracted secret, for the hash function selected by the configuration;
+// with feature `zeroize` it is wiped on drop
+enum Prk {
+    Sha512(ParamHkdf<Sha512>),
+    Sha256(ParamHkdf<Sha256>),
+    Blake2b(ParamHkdf<Blake2b>),
+    Sha3_512(ParamHkdf<Sha3_512>),
+}
+
+/// The public parameters over BLS12-381 whose `hlist` is derived on demand.
+pub type LazyPubParam = GenericLazyPubParam<Bls12>;
+
+/// The public parameters whose `hlist` is derived on demand,
+/// over the pairing engine `E`.
+pub struct GenericLazyPubParam<E: PixelEngine> {
+    config: ParamGenConfig,
+    prk: Prk,
+    g2: E::PixelG2,
+    h: E::PixelG1,
+    // the derived h_0, ..., h_d
+    hlist: Vec<OnceBox<E::PixelG1>>,
+}
+
+impl<E: PixelEngine> GenericLazyPubParam<E> {
+    /// Same as `PubParam::init`, except that `hlist` is derived on demand.
+    pub fn init(seed: &[u8], ciphersuite: u8) -> Result<Self, String> {
+        Self::init_with_config(seed, &ParamGenConfig::new().ciphersuite(ciphersuite))
+    }
+
+    /// Same as `PubParam::init_with_config`, except that `hlist` is derived on demand.
+    /// `h` is derived right away.
+    pub fn init_with_config(seed: &[u8], config: &ParamGenConfig) -> Result<Self, String> {
+        let salt = config.get_dst();
+        let prk = match check_inputs(seed, config)? {
+            KdfHash::Sha512 => Prk::Sha512(ParamKdf::extract(salt, seed)),
+            KdfHash::Sha256 => Prk::Sha256(ParamKdf::extract(salt, seed)),
+            KdfHash::Blake2b => Prk::Blake2b(ParamKdf::extract(salt, seed)),
+            KdfHash::Sha3_512 => Prk::Sha3_512(ParamKdf::extract(salt, seed)),
+        };
+        let mut res = GenericLazyPubParam {
+            config: config.clone(),
+            prk,
+            g2: E::PixelG2::one(),
+            h: E::PixelG1::zero(),
+            hlist: (0..=config.get_depth()).map(|_| OnceBox::new()).collect(),
+        };
+        res.h = res.derive(config.get_h_info())?;
+        Ok(res)
+    }
+
+    fn derive(&self, info: &[u8]) -> Result<E::PixelG1, String> {
+        let ciphersuite = self.config.get_ciphersuite();
+        match &self.prk {
+            Prk::Sha512(hk) => GenericPubParam::<E>::derive_point(hk, info, ciphersuite),
+            Prk::Sha256(hk) => GenericPubParam::<E>::derive_point(hk, info, ciphersuite),
+            Prk::Blake2b(hk) => GenericPubParam::<E>::derive_point(hk, info, ciphersuite),
+            Prk::Sha3_512(hk) => GenericPubParam::<E>::derive_point(hk, info, ciphersuite),
+        }
+    }
+
+    /// Returns the ciphersuite id.
+    pub fn ciphersuite(&self) -> u8 {
+        self.config.get_ciphersuite()
+    }
+
+    /// Returns the depth of the time stamp.
+    pub fn depth(&self) -> usize {
+        self.config.get_depth()
+    }
+
+    /// Returns the `PixelG2` generator.
+    pub fn g2(&self) -> E::PixelG2 {
+        self.g2
+    }
+
+    /// Returns the `h` parameter.
+    pub fn h(&self) -> E::PixelG1 {
+        self.h
+    }
+
+    /// Returns `h_i`, deriving it on its first access.
+    /// Returns an error if `i` exceeds the depth.
+    pub fn hlist(&self, i: usize) -> Result<&E::PixelG1, String> {
+        let cache = match self.hlist.get(i) {
+            Some(c) => c,
+            None => return Err(ERR_HLIST_INDEX.to_owned()),
+        };
+        if let Some(e) = cache.get() {
+            return Ok(e);
+        }
+        let e = self.derive(&self.config.hlist_label(i))?;
+        Ok(cache.get_or_init(|| Box::new(e)))
+    }
+
+    /// Derives the remaining elements of `hlist`, and returns
+    /// the eager public parameters.
+    pub fn to_pub_param(&self) -> Result<GenericPubParam<E>, String> {
+        let mut hlist = [E::PixelG1::zero(); CONST_D + 1];
+        for (i, e) in hlist.iter_mut().enumerate().take(self.depth() + 1) {
+            *e = *self.hlist(i)?;
+        }
+        Ok(GenericPubParam::from_parts(
+            self.depth(),
+            self.ciphersuite(),
+            self.g2,
+            self.h,
+            hlist,
+        ))
+    }
+}
+
+/// The secret is not printed.
+impl<E: PixelEngine> core::fmt::Debug for GenericLazyPubParam<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let derived = self.hlist.iter().filter(|e| e.get().is_some()).count();
+        write!(
+            f,
+            "LazyPubParam {{ depth: {}, ciphersuite: {}, derived hlist: {} }}",
+            self.depth(),
+            self.ciphersuite(),
+            derived
+        )
+    }
+}
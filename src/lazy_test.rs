@@ -0,0 +1,53 @@
+// This module tests that the lazy derivation matches the eager one.
+
+use crate::config::ParamGenConfig;
+use crate::constants::{SHA512_IV, VALID_CIPHERSUITE};
+use crate::lazy::LazyPubParam;
+use crate::PubParam;
+
+// compares the lazy and eager parameters at every index
+fn check_lazy(seed: &[u8], config: &ParamGenConfig) {
+    let pp = PubParam::init_with_config(seed, config).unwrap();
+    let lazy = LazyPubParam::init_with_config(seed, config).unwrap();
+    assert_eq!(lazy.depth(), pp.depth());
+    assert_eq!(lazy.ciphersuite(), pp.ciphersuite());
+    assert_eq!(lazy.g2(), pp.g2());
+    assert_eq!(lazy.h(), pp.h());
+    // in reverse order, so that nothing depends on the order of access
+    for i in (0..=pp.depth()).rev() {
+        assert_eq!(*lazy.hlist(i).unwrap(), pp.hlist()[i]);
+    }
+    assert!(lazy.hlist(pp.depth() + 1).is_err());
+    assert_eq!(lazy.to_pub_param().unwrap(), pp);
+}
+
+#[test]
+fn test_lazy() {
+    // every hash function of the HKDF
+    for &csid in VALID_CIPHERSUITE.iter().step_by(2) {
+        check_lazy(SHA512_IV.as_ref(), &ParamGenConfig::new().ciphersuite(csid));
+    }
+    // custom labels and depth
+    let config = ParamGenConfig::new()
+        .dst(b"chain A")
+        .hlist_info(b"H2G_hlist")
+        .depth(4);
+    check_lazy(&[0x42; 32], &config);
+
+    // the default parameters
+    let lazy = LazyPubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(lazy.to_pub_param().unwrap(), PubParam::default());
+}
+
+#[test]
+fn test_lazy_memoized() {
+    let lazy = LazyPubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[0]).unwrap();
+    assert!(format!("{:?}", lazy).contains("derived hlist: 0"));
+    let h3 = lazy.hlist(3).unwrap();
+    assert!(std::ptr::eq(h3, lazy.hlist(3).unwrap()));
+    assert!(format!("{:?}", lazy).contains("derived hlist: 1"));
+
+    // the same checks as `init`
+    assert!(LazyPubParam::init(&[0u8; 31], VALID_CIPHERSUITE[0]).is_err());
+    assert!(LazyPubParam::init(SHA512_IV.as_ref(), 0xFF).is_err());
+}
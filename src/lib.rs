@@ -1,6 +1,7 @@
 //! This crate implements and hardcodes the public parameters that are
 //! to be used by pixel signature scheme.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "cargo-clippy", deny(warnings))]
 #![cfg_attr(feature = "cargo-clippy", allow(clippy::unreadable_literal))]
 #![deny(missing_debug_implementations)]
@@ -8,47 +9,203 @@
 
 // this file defines the structures for the public parameter
 // and its associated methods
+extern crate ff_zeroize as ff;
 extern crate hkdf;
 extern crate pairing_plus as pairing;
+#[cfg(feature = "zeroize")]
+extern crate zeroize_crate as zeroize;
 
+// without `std` we rely on `alloc` for `Vec` and `String`;
+// with `std` the same paths are re-exported by the standard library
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std as alloc;
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use pairing::bls12_381::Bls12;
 use pairing::hash_to_curve::HashToCurve;
-use pairing::CurveProjective;
+use pairing::{CurveAffine, CurveProjective};
+
+// cache the prepared elements without requiring `std`
+use once_cell::race::OnceBox;
+use prepared::PreparedParam;
+
+// use hkdf to extract and expand a seed;
+// the hash function is selected by the ciphersuite
+use blake2::Blake2b;
+use kdf::{KdfHash, ParamHkdf, ParamKdf};
+use sha2::{Sha256, Sha512};
+use sha3::Sha3_512;
 
-// use hkdf-sha512 to extract and expand a seed
-use hkdf::Hkdf;
-use sha2::Sha512;
+// use sha256 to fingerprint a parameter set
+use sha2::Digest;
 
 /// The trait to serialize and deserialize pixel group elements and
 /// public parameters. The encoding of group elements follows that
 /// of zcash spec.
 pub mod serdes;
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod serdes_test;
 
 // implement the default trait so that we can
 // get default parameter set by PubParam::default()
 mod default;
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod default_test;
 
+// test Eq, Hash, Ord and the constant time comparison
+#[cfg(all(test, feature = "std"))]
+mod eq_test;
+
 // various constants that are to be used.
 mod constants;
 
+/// The configuration of the parameter generation.
+pub mod config;
+#[cfg(test)]
+mod config_test;
+
+/// A commit-reveal ceremony to derive the seed from several parties.
+pub mod ceremony;
+#[cfg(all(test, feature = "std"))]
+mod ceremony_test;
+
+/// Seed derivation from a randomness beacon record.
+pub mod beacon;
+#[cfg(test)]
+mod beacon_test;
+
+// generate parameters from a random seed
+mod random;
+#[cfg(test)]
+mod random_test;
+
+/// The prepared elements of the public parameters, for the Miller loops.
+pub mod prepared;
+#[cfg(all(test, feature = "std"))]
+mod prepared_test;
+
+/// An incremental decoder of the parameter blob.
+pub mod stream;
+#[cfg(all(test, feature = "std"))]
+mod stream_test;
+
+/// Asynchronous reads and writes of the parameter blob over tokio.
+#[cfg(feature = "async")]
+pub mod async_io;
+#[cfg(all(test, feature = "async"))]
+mod async_io_test;
+
+/// Memory-mapped parameter files with lazy decoding of hlist.
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(all(test, feature = "mmap"))]
+mod mmap_test;
+
+/// Public parameters whose hlist is derived on demand.
+pub mod lazy;
+#[cfg(all(test, feature = "std"))]
+mod lazy_test;
+
+/// The cached pairing `e(g2, h)` and the extended parameter file.
+pub mod gt;
+#[cfg(all(test, feature = "std"))]
+mod gt_test;
+
+/// Consistency checks across parameter files.
+#[cfg(feature = "std")]
+pub mod consistency;
+#[cfg(all(test, feature = "std"))]
+mod consistency_test;
+#[cfg(all(test, feature = "std"))]
+mod test_util;
+
+/// The mapping between time stamps and the nodes of the time tree.
+pub mod time;
+#[cfg(all(test, feature = "std"))]
+mod time_test;
+
+/// The policies that a seed must satisfy.
+pub mod policy;
+#[cfg(all(test, feature = "std"))]
+mod policy_test;
+
+/// Seeds that are wiped from memory once dropped.
+#[cfg(feature = "zeroize")]
+pub mod seed;
+#[cfg(all(test, feature = "zeroize", feature = "std"))]
+mod seed_test;
+
+/// INSECURE: parameters with known discrete logs, for tests only.
+#[cfg(feature = "insecure-trapdoor")]
+pub mod trapdoor;
+#[cfg(all(test, feature = "insecure-trapdoor"))]
+mod trapdoor_test;
+
+/// The key derivation functions used during parameter generation.
+pub mod kdf;
+#[cfg(all(test, feature = "std"))]
+mod kdf_test;
+
+/// The pairing engines that the public parameters are defined over.
+pub mod engine;
+#[cfg(test)]
+mod engine_test;
+
+/// WebAssembly bindings for the public parameters.
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(all(test, feature = "wasm", target_arch = "wasm32"))]
+mod wasm_test;
+
+/// C ABI for the public parameters; see `include/pixel_param.h`.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(all(test, feature = "ffi"))]
+mod ffi_test;
+
+/// Python bindings for the public parameters.
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(all(test, feature = "python"))]
+mod python_test;
+
 //  by default the groups are switched so that
 //  the public key lies in G1
 //  this yields smaller public keys
+//  with feature `no_swap` the groups are not switched
+//  this yields smaller signatures
 
 //  additional comments for cargo doc
 /// By default the groups are switched so that
 /// the public key lies in G1.
 /// This means pixel G1 group is mapped to G2 over BLS12-381 curve.
+#[cfg(not(feature = "no_swap"))]
 pub type PixelG1 = pairing::bls12_381::G2;
 //  additional comments for cargo doc
 /// By default the groups are switched so that
 /// the public key lies in G1.
 /// This means pixel G2 group is mapped to G1 over BLS12-381 curve.
+#[cfg(not(feature = "no_swap"))]
 pub type PixelG2 = pairing::bls12_381::G1;
 
+//  additional comments for cargo doc
+/// With feature `no_swap` the groups are not switched so that
+/// the signature lies in G1.
+/// This means pixel G1 group is mapped to G1 over BLS12-381 curve.
+#[cfg(feature = "no_swap")]
+pub type PixelG1 = pairing::bls12_381::G1;
+//  additional comments for cargo doc
+/// With feature `no_swap` the groups are not switched so that
+/// the signature lies in G1.
+/// This means pixel G2 group is mapped to G2 over BLS12-381 curve.
+#[cfg(feature = "no_swap")]
+pub type PixelG2 = pairing::bls12_381::G2;
+
 /// This is a global constant which determines the maximum time
 /// stamp, i.e. `max_time_stamp = 2^D-1`.
 /// For deployment we use a depth = 32 which should be more than
@@ -58,25 +215,70 @@ pub use constants::CONST_D;
 /// This array defines valid ciphersuite identifiers.
 pub use constants::VALID_CIPHERSUITE;
 
+/// This bit of the ciphersuite identifier records that the groups are not switched.
+pub use constants::NO_SWAP_FLAG;
+
 use constants::*;
 
+use engine::Gt;
+/// The trait for the pairing engines that the public parameters are defined over.
+pub use engine::PixelEngine;
+
+/// The configuration of the parameter generation.
+pub use config::ParamGenConfig;
+
+/// The time stamps and the paths of the time tree.
+pub use time::{TimeStamp, TimeVec};
+
+/// The seed policies.
+pub use policy::{InitError, LengthPolicy, SeedError, SeedPolicy, StrictPolicy};
+
+/// The public parameters whose hlist is derived on demand.
+pub use lazy::{GenericLazyPubParam, LazyPubParam};
+
+/// The trait to compare public parameters in constant time.
+pub use subtle::{Choice, ConstantTimeEq};
+
+/// A seed that is zeroized on drop.
+#[cfg(feature = "zeroize")]
+pub use seed::SecretSeed;
+
 /// Expose the length of public key.
-pub use serdes::{SerDes, PP_LEN_COMPRESSED, PP_LEN_UNCOMPRESSED};
+pub use serdes::{PP_LEN_COMPRESSED, PP_LEN_UNCOMPRESSED};
+
+/// The trait to serialize and deserialize over `std::io`.
+#[cfg(feature = "std")]
+pub use serdes::SerDes;
+
+/// The public parameter over BLS12-381, with the groups
+/// `PixelG1` and `PixelG2` given by feature `no_swap`.
+pub type PubParam = GenericPubParam<Bls12>;
 
 /// The public parameter consists of the following ...
 /// * g2: group generators for `PixelG2` group
 /// * h: a `PixelG1` element,
 /// * hlist: D+1 PixelG1 elements `h_0, h_1, ..., h_d`
-#[derive(Clone)]
-pub struct PubParam {
+///
+/// The groups are given by the pairing engine `E`; see `PubParam` for BLS12-381.
+pub struct GenericPubParam<E: PixelEngine> {
     depth: usize, // the depth of the time vector
     ciphersuite: u8,
-    g2: PixelG2,
-    h: PixelG1,                    // h
-    hlist: [PixelG1; CONST_D + 1], // h_0, h_1, ..., h_d
+    g2: E::PixelG2,
+    h: E::PixelG1,                    // h
+    hlist: [E::PixelG1; CONST_D + 1], // h_0, h_1, ..., h_d
+    // normalized copies of g2, h and hlist
+    g2_affine: <E::PixelG2 as CurveProjective>::Affine,
+    h_affine: <E::PixelG1 as CurveProjective>::Affine,
+    hlist_affine: [<E::PixelG1 as CurveProjective>::Affine; CONST_D + 1],
+    // prepared h and hlist, computed on demand
+    prepared: OnceBox<PreparedParam<E>>,
+    // e(g2, h), computed on demand
+    gt_g2_h: OnceBox<Gt<E>>,
+    // the compressed encoding, computed on demand
+    encoded: OnceBox<Vec<u8>>,
 }
 
-impl PubParam {
+impl<E: PixelEngine> GenericPubParam<E> {
     /// get the cipher suite id from the public param
     pub fn ciphersuite(&self) -> u8 {
         self.ciphersuite
@@ -88,29 +290,98 @@ impl PubParam {
     }
 
     /// Returns the `PixelG2` generator.
-    pub fn g2(&self) -> PixelG2 {
+    pub fn g2(&self) -> E::PixelG2 {
         self.g2
     }
 
     /// Returns the `h` parmeter, i.e., the first `PixelG1` element of the public param.
-    pub fn h(&self) -> PixelG1 {
+    pub fn h(&self) -> E::PixelG1 {
         self.h
     }
 
     /// Returns the list of `PixelG1` elements of the public param.
-    pub fn hlist(&self) -> [PixelG1; CONST_D + 1] {
+    pub fn hlist(&self) -> [E::PixelG1; CONST_D + 1] {
         self.hlist
     }
 
+    /// Returns a reference to `h_0, ..., h_d`, without copying.
+    pub fn hlist_ref(&self) -> &[E::PixelG1] {
+        &self.hlist[..=self.depth]
+    }
+
+    /// Returns `h_0, ..., h_d` in affine form.
+    pub fn hlist_affine(&self) -> &[<E::PixelG1 as CurveProjective>::Affine] {
+        &self.hlist_affine[..=self.depth]
+    }
+
+    /// Returns `h` in affine form.
+    pub fn h_affine(&self) -> &<E::PixelG1 as CurveProjective>::Affine {
+        &self.h_affine
+    }
+
+    /// Returns the `PixelG2` generator in affine form.
+    pub fn g2_affine(&self) -> &<E::PixelG2 as CurveProjective>::Affine {
+        &self.g2_affine
+    }
+
+    /// Builds the public param from its elements, and
+    /// keeps their normalized copies in affine form.
+    /// The entries of hlist beyond the depth are expected to be zero.
+    pub(crate) fn from_parts(
+        depth: usize,
+        ciphersuite: u8,
+        g2: E::PixelG2,
+        h: E::PixelG1,
+        hlist: [E::PixelG1; CONST_D + 1],
+    ) -> Self {
+        let mut hlist_affine = [<E::PixelG1 as CurveProjective>::Affine::zero(); CONST_D + 1];
+        for (a, e) in hlist_affine.iter_mut().zip(hlist.iter()) {
+            *a = e.into_affine();
+        }
+        GenericPubParam {
+            depth,
+            ciphersuite,
+            g2,
+            h,
+            hlist,
+            g2_affine: g2.into_affine(),
+            h_affine: h.into_affine(),
+            hlist_affine,
+            prepared: OnceBox::new(),
+            gt_g2_h: OnceBox::new(),
+            encoded: OnceBox::new(),
+        }
+    }
+
+    /// Returns the compressed encoding of the public param, i.e.,
+    /// the blob of `to_bytes(true)` without the ciphersuite check.
+    /// It is computed on the first call and cached afterwards.
+    pub(crate) fn encoded(&self) -> &[u8] {
+        self.encoded
+            .get_or_init(|| alloc::boxed::Box::new(self.encode(true)))
+    }
+
+    /// Returns the fingerprint of the public param, i.e.,
+    /// the SHA-256 digest of its compressed serialization.
+    /// Two parameter sets are identical if and only if
+    /// their fingerprints match.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let digest = Sha256::digest(self.encoded());
+        let mut res = [0u8; 32];
+        res.copy_from_slice(digest.as_slice());
+        res
+    }
+
     /// This function initialize the parameter with a default seed
-    /// which is tentatively set to SHA512's initial vector
+    /// which is tentatively set to SHA512's initial vector,
+    /// and the first valid ciphersuite id
     pub fn init_without_seed() -> Self {
-        Self::init(SHA512_IV.as_ref(), 0).unwrap()
+        Self::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[0]).unwrap()
     }
 
     /// This function takes a seed, and a ciphersuite id, and outputs the
     /// public parameters as follows:
-    /// 1. `g2 = PixelG2::one` <- this is the default generator of bls12-381 curve
+    /// 1. `g2 = PixelG2::one` <- this is the default generator of the curve
     /// 2. extract the randomness from the seed:
     ///     `m = HKDF-Extract(DOM_SEP_PARAM_GEN , seed)`
     /// 3. generate `h` as follows
@@ -122,56 +393,111 @@ impl PubParam {
     ///     * `t = HKDF-Expand(m, info, 32)`
     ///     * `h = hash_to_group(t, ciphersuite)`
     ///
+    /// The hash function of the HKDF is selected by the ciphersuite,
+    /// see `KdfHash::from_ciphersuite`.
+    ///
+    /// The seed is any byte string, e.g., a `&[u8]`, or with feature `zeroize`
+    /// a `SecretSeed`, which is wiped from memory once dropped.
+    ///
     /// It returns an error if the ciphersuite is not supported,
     /// or if the seed does not have enough entropy -- must be at least 32 bytes.
-    pub fn init(seed: &[u8], ciphersuite: u8) -> Result<Self, String> {
-        // make sure we have enough entropy
-        if seed.len() < 32 {
-            return Err(ERR_SEED_TOO_SHORT.to_owned());
-        }
-        // make sure the ciphersuite is valid    <- the valid list is tentitive
-        if !VALID_CIPHERSUITE.contains(&ciphersuite) {
-            return Err(ERR_CIPHERSUITE.to_owned());
+    pub fn init<S: AsRef<[u8]>>(seed: S, ciphersuite: u8) -> Result<Self, String> {
+        Self::init_with_config(seed, &ParamGenConfig::new().ciphersuite(ciphersuite))
+    }
+
+    /// This function takes a seed and a configuration, and outputs the
+    /// public parameters following the same steps as `init`, where
+    /// `DOM_SEP_PARAM_GEN`, the info labels, the depth, the ciphersuite id
+    /// and the hash function of the HKDF are given by the configuration.
+    /// With the default configuration, this function is the same as `init`.
+    ///
+    /// It returns an error if the ciphersuite is not supported,
+    /// if the depth exceeds `CONST_D`,
+    /// or if the seed does not have enough entropy -- must be at least 32 bytes.
+    pub fn init_with_config<S: AsRef<[u8]>>(
+        seed: S,
+        config: &ParamGenConfig,
+    ) -> Result<Self, String> {
+        let seed = seed.as_ref();
+        match check_inputs(seed, config)? {
+            KdfHash::Sha512 => Self::init_with_kdf::<ParamHkdf<Sha512>>(seed, config),
+            KdfHash::Sha256 => Self::init_with_kdf::<ParamHkdf<Sha256>>(seed, config),
+            KdfHash::Blake2b => Self::init_with_kdf::<ParamHkdf<Blake2b>>(seed, config),
+            KdfHash::Sha3_512 => Self::init_with_kdf::<ParamHkdf<Sha3_512>>(seed, config),
         }
-        // instantiate the HKDF with a seed and a public salt.
-        let salt = constants::DOM_SEP_PARAM_GEN;
-        let hk = Hkdf::<Sha512>::extract(Some(salt.as_ref()), &seed);
+    }
+
+    /// This function generates the public parameters with a given KDF;
+    /// the inputs are assumed to be checked by `init_with_config`.
+    fn init_with_kdf<K: ParamKdf>(seed: &[u8], config: &ParamGenConfig) -> Result<Self, String> {
+        let ciphersuite = config.get_ciphersuite();
+        let depth = config.get_depth();
+
+        // instantiate the HKDF with a seed and a public salt;
+        // the secret is wiped when `hk` is dropped, including on errors
+        let salt = config.get_dst();
+        let hk = K::extract(salt, &seed);
 
         // generate h
-        let info = b"H2G_h";
-        let mut hkdf_output = [0u8; 32];
-        assert!(
-            // HKDF-Expand(m, info, 32)
-            hk.expand(info, &mut hkdf_output).is_ok(),
-            "Error getting output from HKDF"
-        );
-        // use hash to curve to get a group element
-        let h = PixelG1::hash_to_curve(hkdf_output, &[ciphersuite]);
-        // generate hlist
-        let mut hlist: Vec<PixelG1> = Vec::with_capacity(CONST_D + 1);
-        for i in 0..=CONST_D {
-            let info = [b"H2G_h", [i as u8].as_ref()].concat();
-            hkdf_output = [0u8; 32];
-            assert!(
-                // HKDF-Expand(m, info, 32)
-                hk.expand(&info, &mut hkdf_output).is_ok(),
-                "Error getting output from HKDF"
-            );
-            // use hash to curve to get a group element
-            let hi = PixelG1::hash_to_curve(hkdf_output, &[ciphersuite]);
-            hlist.push(hi);
+        let h = Self::derive_point(&hk, config.get_h_info(), ciphersuite)?;
+        // generate hlist; the entries beyond the depth remain zero
+        let mut hlist_array: [E::PixelG1; CONST_D + 1] = [E::PixelG1::zero(); CONST_D + 1];
+        for (i, e) in hlist_array.iter_mut().enumerate().take(depth + 1) {
+            *e = Self::derive_point(&hk, &config.hlist_label(i), ciphersuite)?;
         }
-        let mut hlist_array: [PixelG1; CONST_D + 1] = [PixelG1::zero(); CONST_D + 1];
-        hlist_array.copy_from_slice(&hlist);
 
         // format the ouput
-        Ok(PubParam {
-            depth: CONST_D,
+        Ok(Self::from_parts(
+            depth,
             ciphersuite,
-            g2: PixelG2::one(),
+            E::PixelG2::one(),
             h,
-            hlist: hlist_array,
-        })
+            hlist_array,
+        ))
+    }
+
+    /// This function derives a group element from the extracted secret:
+    /// `hash_to_group(HKDF-Expand(m, info, 32), ciphersuite)`.
+    pub(crate) fn derive_point<K: ParamKdf>(
+        hk: &K,
+        info: &[u8],
+        ciphersuite: u8,
+    ) -> Result<E::PixelG1, String> {
+        // the intermediate secret is wiped on drop, including on errors;
+        // it stays in one heap block, so that no copy is left behind by moves
+        #[cfg(feature = "zeroize")]
+        let mut hkdf_output = zeroize::Zeroizing::new(alloc::vec![0u8; 32]);
+        #[cfg(not(feature = "zeroize"))]
+        let mut hkdf_output = [0u8; 32];
+        // HKDF-Expand(m, info, 32)
+        hk.expand(info, &mut hkdf_output[..])?;
+        // use hash to curve to get a group element
+        Ok(E::PixelG1::hash_to_curve(&hkdf_output[..], &[ciphersuite]))
+    }
+
+    /// This function derives the parameters for a shallower time tree:
+    /// it keeps `g2`, `h` and `h_0, ..., h_{new_depth}`, and records the new depth,
+    /// so that the parameters serialize with the `new_depth` byte.
+    /// The result is the same as generating the parameters from the same seed
+    /// with depth `new_depth`.
+    ///
+    /// It returns an error if `new_depth` exceeds the current depth.
+    pub fn truncate(&self, new_depth: usize) -> Result<Self, String> {
+        if new_depth > self.depth {
+            return Err(ERR_TRUNCATE_DEPTH.to_owned());
+        }
+        // the entries beyond the depth are zero, as in `init_with_config`
+        let mut hlist = self.hlist;
+        for e in hlist.iter_mut().skip(new_depth + 1) {
+            *e = E::PixelG1::zero();
+        }
+        Ok(Self::from_parts(
+            new_depth,
+            self.ciphersuite,
+            self.g2,
+            self.h,
+            hlist,
+        ))
     }
 
     /// This function returns the storage requirement for this Public parameter. Recall that
@@ -181,28 +507,70 @@ impl PubParam {
     /// Return 2 + serial ...
     //  This code is the same as the constant PP_LEN_(UN)COMPRESSED
     pub fn size(&self, compressed: bool) -> usize {
-        let mut len = 0;
-        let pixel_g1_size = 96;
-
-        // g2r and hpoly length
-        // this will be a G1 and a G2
-        len += 144;
-        // hv length = |hv| * pixel g1 size
-        len += (self.depth() + 1) * pixel_g1_size;
-        if compressed {
-            // additional 2 bytes for ciphersuite and depth
-            len + 2
-        } else {
-            // len is double since the pp is not compressed
-            // additional 2 bytes for ciphersuite and depth
-            len * 2 + 2
+        // 2 bytes for ciphersuite and depth, a PixelG2 element for g2,
+        // and (depth + 2) PixelG1 elements for h and hlist;
+        // the element sizes are the encoded point sizes of the engine
+        serdes::serialized_len::<E>(self.depth(), compressed)
+    }
+}
+
+/// This function checks the inputs of the parameter generation,
+/// and returns the hash function of the HKDF.
+pub(crate) fn check_inputs(seed: &[u8], config: &ParamGenConfig) -> Result<KdfHash, String> {
+    // make sure we have enough entropy
+    if seed.len() < 32 {
+        return Err(ERR_SEED_TOO_SHORT.to_owned());
+    }
+    // make sure the depth fits in hlist
+    if config.get_depth() > CONST_D {
+        return Err(ERR_CONFIG_DEPTH.to_owned());
+    }
+    // make sure the ciphersuite is valid    <- the valid list is tentitive
+    // and select the hash function for the HKDF
+    let hash = KdfHash::from_ciphersuite(config.get_ciphersuite())?;
+    // the blob only records the ciphersuite id, so the hash function
+    // that is explicitly set must be the one the id selects
+    match config.get_hash() {
+        Some(h) if h != hash => Err(ERR_CONFIG_HASH.to_owned()),
+        _ => Ok(hash),
+    }
+}
+
+/// The cached prepared elements, pairing value and encoding are cloned
+/// along with the parameters.
+impl<E: PixelEngine> Clone for GenericPubParam<E> {
+    fn clone(&self) -> Self {
+        let prepared = OnceBox::new();
+        if let Some(p) = self.prepared.get() {
+            let _ = prepared.set(alloc::boxed::Box::new(p.clone()));
+        }
+        let gt_g2_h = OnceBox::new();
+        if let Some(gt) = self.gt_g2_h.get() {
+            let _ = gt_g2_h.set(alloc::boxed::Box::new(*gt));
+        }
+        let encoded = OnceBox::new();
+        if let Some(bytes) = self.encoded.get() {
+            let _ = encoded.set(alloc::boxed::Box::new(bytes.clone()));
+        }
+        GenericPubParam {
+            depth: self.depth,
+            ciphersuite: self.ciphersuite,
+            g2: self.g2,
+            h: self.h,
+            hlist: self.hlist,
+            g2_affine: self.g2_affine,
+            h_affine: self.h_affine,
+            hlist_affine: self.hlist_affine,
+            prepared,
+            gt_g2_h,
+            encoded,
         }
     }
 }
 
 /// convenient function to debug public parameter objects
-impl std::fmt::Debug for PubParam {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl<E: PixelEngine> core::fmt::Debug for GenericPubParam<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(
             f,
             "================================\n\
@@ -214,27 +582,57 @@ impl std::fmt::Debug for PubParam {
             //            self.g1.into_affine(),
             self.depth,
             self.ciphersuite,
-            self.g2.into_affine(),
-            self.h.into_affine(),
+            self.g2_affine,
+            self.h_affine,
         )?;
-        for i in 0..=CONST_D {
-            writeln!(f, "hlist: h{}: {:#?}", i, self.hlist[i].into_affine())?;
+        for i in 0..=self.depth {
+            writeln!(f, "hlist: h{}: {:#?}", i, self.hlist_affine[i])?;
         }
         writeln!(f, "================================")
     }
 }
 
-/// convenient function to compare public parameter objects
-impl std::cmp::PartialEq for PubParam {
+/// `==` goes through `ct_eq`, so it runs in constant time
+/// except for the depths.
+impl<E: PixelEngine> core::cmp::PartialEq for GenericPubParam<E> {
     fn eq(&self, other: &Self) -> bool {
-        if self.depth != other.depth {
-            return false;
-        }
-        for i in 0..=self.depth {
-            if self.hlist[i] != other.hlist[i] {
-                return false;
-            }
-        }
-        self.ciphersuite == other.ciphersuite && self.g2 == other.g2 && self.h == other.h
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl<E: PixelEngine> core::cmp::Eq for GenericPubParam<E> {}
+
+/// The hash is computed over the canonical compressed encoding,
+/// so that equal parameters have equal hashes.
+/// The encoding is cached, see `encoded`.
+impl<E: PixelEngine> core::hash::Hash for GenericPubParam<E> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write(self.encoded());
+    }
+}
+
+/// The parameters are ordered by their compressed encodings, i.e.,
+/// by ciphersuite id, then by depth, then by the encoded elements.
+/// The encoding is cached, see `encoded`.
+impl<E: PixelEngine> core::cmp::Ord for GenericPubParam<E> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.encoded().cmp(other.encoded())
+    }
+}
+
+impl<E: PixelEngine> core::cmp::PartialOrd for GenericPubParam<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the compressed encodings in constant time.
+/// The encodings are cached, so that only the first comparison
+/// of a parameter set encodes its elements.
+/// Only the lengths, i.e., the depths, may leak through timing.
+impl<E: PixelEngine> ConstantTimeEq for GenericPubParam<E> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // encodings of different lengths are unequal
+        self.encoded().ct_eq(other.encoded())
     }
 }
@@ -0,0 +1,134 @@
+// This module loads the parameters from a memory-mapped file.
+// The header, `g2` and `h` are decoded when the file is opened;
+// each `h_i` is decoded on its first access and cached, so that
+// a process that only touches a few `h_i` never decodes the whole list.
+// `validate` decodes and checks the whole file.
+
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::boxed::Box;
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec::Vec;
+use crate::constants::{ERR_HLIST_INDEX, ERR_SERIAL_LEN};
+use crate::serdes::{check_header, point_len, read_point, serialized_len};
+use crate::{GenericPubParam, PixelEngine};
+use memmap2::Mmap;
+use once_cell::race::OnceBox;
+use pairing::bls12_381::Bls12;
+use std::fs::File;
+use std::path::Path;
+
+/// The public parameters backed by a memory-mapped file.
+pub struct MmapPubParam<E: PixelEngine = Bls12> {
+    mmap: Mmap,
+    compressed: bool,
+    depth: usize,
+    ciphersuite: u8,
+    g2: E::PixelG2,
+    h: E::PixelG1,
+    // the decoded h_0, ..., h_d
+    hlist: Vec<OnceBox<E::PixelG1>>,
+}
+
+impl<E: PixelEngine> GenericPubParam<E> {
+    /// Maps a parameter file; the file is compressed if its length is
+    /// that of a compressed blob with the depth of the file.
+    /// The elements of hlist are decoded on demand.
+    ///
+    /// Returns an error if the file cannot be mapped, if the header is invalid,
+    /// if the length is incorrect, or if `g2` or `h` is invalid.
+    /// The file must not be modified while it is mapped.
+    pub fn open_mmap<P: AsRef<Path>>(path: P) -> Result<MmapPubParam<E>, String> {
+        let file = match File::open(path) {
+            Ok(f) => f,
+            Err(e) => return Err(e.to_string()),
+        };
+        // safe as long as the file is not modified while mapped
+        let mmap = match unsafe { Mmap::map(&file) } {
+            Ok(m) => m,
+            Err(e) => return Err(e.to_string()),
+        };
+        if mmap.len() < 2 {
+            return Err(ERR_SERIAL_LEN.to_owned());
+        }
+        let compressed = mmap.len() == serialized_len::<E>(mmap[1] as usize, true);
+        if mmap.len() != check_header::<E>([mmap[0], mmap[1]], compressed)? {
+            return Err(ERR_SERIAL_LEN.to_owned());
+        }
+        let depth = mmap[1] as usize;
+
+        let mut rest = &mmap[2..];
+        let g2: E::PixelG2 = read_point(&mut rest, compressed)?;
+        let h: E::PixelG1 = read_point(&mut rest, compressed)?;
+        Ok(MmapPubParam {
+            compressed,
+            depth,
+            ciphersuite: mmap[0],
+            g2,
+            h,
+            hlist: (0..=depth).map(|_| OnceBox::new()).collect(),
+            mmap,
+        })
+    }
+}
+
+impl<E: PixelEngine> MmapPubParam<E> {
+    /// Returns the ciphersuite id.
+    pub fn ciphersuite(&self) -> u8 {
+        self.ciphersuite
+    }
+
+    /// Returns the depth of the time stamp.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns true if the file is compressed.
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// Returns the `PixelG2` generator.
+    pub fn g2(&self) -> E::PixelG2 {
+        self.g2
+    }
+
+    /// Returns the `h` parameter.
+    pub fn h(&self) -> E::PixelG1 {
+        self.h
+    }
+
+    /// Returns `h_i`, decoding it on its first access.
+    /// Returns an error if `i` exceeds the depth, or if the element is invalid;
+    /// an invalid element is not cached.
+    pub fn hlist(&self, i: usize) -> Result<&E::PixelG1, String> {
+        let cache = match self.hlist.get(i) {
+            Some(c) => c,
+            None => return Err(ERR_HLIST_INDEX.to_owned()),
+        };
+        if let Some(e) = cache.get() {
+            return Ok(e);
+        }
+        let g1_len = point_len::<E::PixelG1>(self.compressed);
+        let offset = 2 + point_len::<E::PixelG2>(self.compressed) + (i + 1) * g1_len;
+        let e: E::PixelG1 = read_point(&mut &self.mmap[offset..offset + g1_len], self.compressed)?;
+        Ok(cache.get_or_init(|| Box::new(e)))
+    }
+
+    /// Decodes and checks the whole file, with the same checks as `from_bytes`,
+    /// and returns the parameters.
+    pub fn validate(&self) -> Result<GenericPubParam<E>, String> {
+        GenericPubParam::from_bytes(&self.mmap, self.compressed)
+    }
+}
+
+/// The mapped bytes are not printed.
+impl<E: PixelEngine> core::fmt::Debug for MmapPubParam<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let decoded = self.hlist.iter().filter(|e| e.get().is_some()).count();
+        write!(
+            f,
+            "MmapPubParam {{ depth: {}, ciphersuite: {}, compressed: {}, decoded hlist: {} }}",
+            self.depth, self.ciphersuite, self.compressed, decoded
+        )
+    }
+}
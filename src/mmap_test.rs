@@ -0,0 +1,70 @@
+// This module tests the memory-mapped parameter files.
+
+use crate::test_util::write_file;
+use crate::PubParam;
+
+#[test]
+fn test_open_mmap() {
+    let pp = PubParam::default();
+    for &compressed in [true, false].iter() {
+        let path = write_file(
+            "mmap",
+            &format!("{}", compressed),
+            &pp.to_bytes(compressed).unwrap(),
+        );
+        let mapped = PubParam::open_mmap(&path).unwrap();
+        assert_eq!(mapped.is_compressed(), compressed);
+        assert_eq!(mapped.depth(), pp.depth());
+        assert_eq!(mapped.ciphersuite(), pp.ciphersuite());
+        assert_eq!(mapped.g2(), pp.g2());
+        assert_eq!(mapped.h(), pp.h());
+
+        // decoded on demand, and cached
+        let h5 = mapped.hlist(5).unwrap();
+        assert_eq!(*h5, pp.hlist()[5]);
+        assert!(std::ptr::eq(h5, mapped.hlist(5).unwrap()));
+        assert!(format!("{:?}", mapped).contains("decoded hlist: 1"));
+        for i in 0..=pp.depth() {
+            assert_eq!(*mapped.hlist(i).unwrap(), pp.hlist()[i]);
+        }
+        assert!(mapped.hlist(pp.depth() + 1).is_err());
+
+        assert_eq!(mapped.validate().unwrap(), pp);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
+
+#[test]
+fn test_open_mmap_errors() {
+    let pp = PubParam::default();
+    let bytes = pp.to_bytes(true).unwrap();
+
+    // an invalid h_i is only reported on its access, and by validate
+    let mut bad = bytes.clone();
+    let len = bad.len();
+    bad[len - 40] ^= 0xFF;
+    let path = write_file("mmap", "bad_hlist", &bad);
+    let mapped = PubParam::open_mmap(&path).unwrap();
+    assert_eq!(*mapped.hlist(0).unwrap(), pp.hlist()[0]);
+    assert!(mapped.hlist(pp.depth()).is_err());
+    assert!(mapped.validate().is_err());
+    std::fs::remove_file(&path).unwrap();
+
+    // invalid header, length or g2
+    let path = write_file("mmap", "bad_len", &bytes[..100]);
+    assert!(PubParam::open_mmap(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+    let mut bad = bytes.clone();
+    bad[0] = 0xFF;
+    let path = write_file("mmap", "bad_csid", &bad);
+    assert!(PubParam::open_mmap(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+    let mut bad = bytes.clone();
+    bad[10] ^= 0xFF;
+    let path = write_file("mmap", "bad_g2", &bad);
+    assert!(PubParam::open_mmap(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+
+    let missing = std::env::temp_dir().join("pixel_param_mmap_missing");
+    assert!(PubParam::open_mmap(&missing).is_err());
+}
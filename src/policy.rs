@@ -0,0 +1,197 @@
+// This module defines the policies that a seed must satisfy before
+// it is used for the parameter generation.
+// `init` only requires the seed to be at least 32 bytes long,
+// which is what `LengthPolicy` checks. `StrictPolicy` additionally
+// rejects seeds that are obviously degenerate, and is meant for
+// production: a seed that is a single repeated byte, that repeats
+// a short pattern, or that is a known test seed such as `SHA512_IV`.
+
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::string::String;
+use crate::constants::{
+    ERR_SEED_CONSTANT, ERR_SEED_KNOWN, ERR_SEED_REPEATING, ERR_SEED_TOO_SHORT, SHA512_IV,
+};
+use crate::{GenericPubParam, PixelEngine};
+
+/// The seeds that are public, e.g., used for tests or for the default parameters.
+pub const KNOWN_TEST_SEEDS: [&[u8]; 1] = [&SHA512_IV];
+
+/// The reasons for which a policy rejects a seed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeedError {
+    /// The seed is shorter than the minimum length.
+    TooShort {
+        /// The minimum length.
+        min_len: usize,
+        /// The length of the seed.
+        len: usize,
+    },
+    /// The seed is a single repeated byte.
+    Constant,
+    /// The seed repeats a pattern of `period` bytes.
+    Repeating {
+        /// The length of the pattern.
+        period: usize,
+    },
+    /// The seed is a known test seed.
+    KnownTestSeed,
+}
+
+impl core::fmt::Display for SeedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            SeedError::TooShort { .. } => write!(f, "{}", ERR_SEED_TOO_SHORT),
+            SeedError::Constant => write!(f, "{}", ERR_SEED_CONSTANT),
+            SeedError::Repeating { .. } => write!(f, "{}", ERR_SEED_REPEATING),
+            SeedError::KnownTestSeed => write!(f, "{}", ERR_SEED_KNOWN),
+        }
+    }
+}
+
+/// The error messages of the crate are strings;
+/// a `SeedError` converts to the corresponding message.
+impl From<SeedError> for String {
+    fn from(e: SeedError) -> String {
+        match e {
+            SeedError::TooShort { .. } => ERR_SEED_TOO_SHORT.to_owned(),
+            SeedError::Constant => ERR_SEED_CONSTANT.to_owned(),
+            SeedError::Repeating { .. } => ERR_SEED_REPEATING.to_owned(),
+            SeedError::KnownTestSeed => ERR_SEED_KNOWN.to_owned(),
+        }
+    }
+}
+
+/// The errors of `init_with_policy`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InitError {
+    /// The seed violates the policy.
+    Seed(SeedError),
+    /// The parameter generation failed, with the error message of `init`.
+    Init(String),
+}
+
+impl core::fmt::Display for InitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            InitError::Seed(e) => write!(f, "{}", e),
+            InitError::Init(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<SeedError> for InitError {
+    fn from(e: SeedError) -> InitError {
+        InitError::Seed(e)
+    }
+}
+
+/// Converts to the error message, as returned by the rest of the crate.
+impl From<InitError> for String {
+    fn from(e: InitError) -> String {
+        match e {
+            InitError::Seed(e) => e.into(),
+            InitError::Init(e) => e,
+        }
+    }
+}
+
+/// A policy that a seed must satisfy.
+pub trait SeedPolicy {
+    /// Returns an error if the seed violates the policy.
+    fn check(&self, seed: &[u8]) -> Result<(), SeedError>;
+}
+
+/// The policy of `init`: the seed must be at least 32 bytes long.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LengthPolicy;
+
+impl SeedPolicy for LengthPolicy {
+    fn check(&self, seed: &[u8]) -> Result<(), SeedError> {
+        check_len(seed, 32)
+    }
+}
+
+/// The policy for production seeds. A seed is rejected if
+/// * it is shorter than `min_len` bytes, 32 by default
+/// * it is a single repeated byte
+/// * it repeats a pattern of at most `max_period` bytes, 16 by default
+/// * it is one of `KNOWN_TEST_SEEDS`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StrictPolicy {
+    min_len: usize,
+    max_period: usize,
+}
+
+impl Default for StrictPolicy {
+    fn default() -> Self {
+        StrictPolicy {
+            min_len: 32,
+            max_period: 16,
+        }
+    }
+}
+
+impl StrictPolicy {
+    /// Returns the strict policy with the default bounds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the minimum length of a seed.
+    /// Note that `init` rejects seeds shorter than 32 bytes regardless.
+    pub fn min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len;
+        self
+    }
+
+    /// Sets the maximum length of the patterns that are rejected.
+    pub fn max_period(mut self, max_period: usize) -> Self {
+        self.max_period = max_period;
+        self
+    }
+}
+
+impl SeedPolicy for StrictPolicy {
+    fn check(&self, seed: &[u8]) -> Result<(), SeedError> {
+        check_len(seed, self.min_len)?;
+        if KNOWN_TEST_SEEDS.iter().any(|s| *s == seed) {
+            return Err(SeedError::KnownTestSeed);
+        }
+        // find the shortest period of the seed, if short enough
+        for period in 1..=self.max_period.min(seed.len() / 2) {
+            if seed[period..].iter().zip(seed.iter()).all(|(a, b)| a == b) {
+                return Err(if period == 1 {
+                    SeedError::Constant
+                } else {
+                    SeedError::Repeating { period }
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_len(seed: &[u8], min_len: usize) -> Result<(), SeedError> {
+    if seed.len() < min_len {
+        return Err(SeedError::TooShort {
+            min_len,
+            len: seed.len(),
+        });
+    }
+    Ok(())
+}
+
+impl<E: PixelEngine> GenericPubParam<E> {
+    /// Same as `init`, but the seed is first checked against the policy.
+    /// It returns `InitError::Seed` if the seed violates the policy,
+    /// or `InitError::Init` under the same conditions as `init`.
+    pub fn init_with_policy<S: AsRef<[u8]>, P: SeedPolicy>(
+        seed: S,
+        ciphersuite: u8,
+        policy: &P,
+    ) -> Result<Self, InitError> {
+        let seed = seed.as_ref();
+        policy.check(seed)?;
+        Self::init(seed, ciphersuite).map_err(InitError::Init)
+    }
+}
@@ -0,0 +1,105 @@
+// This module tests the seed policies.
+
+use crate::constants::ERR_CIPHERSUITE;
+use crate::constants::{SHA512_IV, VALID_CIPHERSUITE};
+use crate::policy::{InitError, LengthPolicy, SeedError, SeedPolicy, StrictPolicy};
+use crate::PubParam;
+
+#[test]
+fn test_length_policy() {
+    // the length policy is the check of `init`
+    assert_eq!(
+        LengthPolicy.check(&[1u8; 31]),
+        Err(SeedError::TooShort {
+            min_len: 32,
+            len: 31
+        })
+    );
+    assert_eq!(LengthPolicy.check(&[0u8; 32]), Ok(()));
+    assert_eq!(LengthPolicy.check(SHA512_IV.as_ref()), Ok(()));
+}
+
+#[test]
+fn test_strict_policy() {
+    let policy = StrictPolicy::new();
+    let mut seed = [0u8; 64];
+    for (i, e) in seed.iter_mut().enumerate() {
+        *e = (i * 7 + 3) as u8 ^ 0x5c;
+    }
+    assert_eq!(policy.check(&seed), Ok(()));
+
+    // degenerate seeds
+    assert_eq!(policy.check(&[0u8; 32]), Err(SeedError::Constant));
+    assert_eq!(policy.check(&[0xAB; 64]), Err(SeedError::Constant));
+    let pattern: Vec<u8> = b"0123456789abcdef"
+        .iter()
+        .cycle()
+        .take(40)
+        .cloned()
+        .collect();
+    assert_eq!(
+        policy.check(&pattern),
+        Err(SeedError::Repeating { period: 16 })
+    );
+    let pattern: Vec<u8> = [1u8, 2].iter().cycle().take(33).cloned().collect();
+    assert_eq!(
+        policy.check(&pattern),
+        Err(SeedError::Repeating { period: 2 })
+    );
+    assert_eq!(
+        policy.check(SHA512_IV.as_ref()),
+        Err(SeedError::KnownTestSeed)
+    );
+
+    // longer patterns are only rejected on demand
+    let pattern: Vec<u8> = seed[..20].iter().cycle().take(64).cloned().collect();
+    assert_eq!(policy.check(&pattern), Ok(()));
+    assert_eq!(
+        policy.max_period(20).check(&pattern),
+        Err(SeedError::Repeating { period: 20 })
+    );
+
+    // configurable minimum length
+    assert_eq!(
+        policy.min_len(128).check(&seed),
+        Err(SeedError::TooShort {
+            min_len: 128,
+            len: 64
+        })
+    );
+}
+
+#[test]
+fn test_init_with_policy() {
+    let policy = StrictPolicy::new();
+    let mut seed = [0u8; 64];
+    seed.copy_from_slice(SHA512_IV.as_ref());
+    seed[0] ^= 1;
+    let pp = PubParam::init_with_policy(&seed, VALID_CIPHERSUITE[0], &policy).unwrap();
+    let pp_init = PubParam::init(&seed, VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp, pp_init);
+
+    // the violations come back as typed errors
+    let err =
+        PubParam::init_with_policy(SHA512_IV.as_ref(), VALID_CIPHERSUITE[0], &policy).unwrap_err();
+    assert_eq!(err, InitError::Seed(SeedError::KnownTestSeed));
+    assert_eq!(err.to_string(), SeedError::KnownTestSeed.to_string());
+    assert_eq!(String::from(err), String::from(SeedError::KnownTestSeed));
+    assert!(PubParam::init_with_policy(&[0u8; 32], VALID_CIPHERSUITE[0], &LengthPolicy).is_ok());
+    assert_eq!(
+        PubParam::init_with_policy(&[0u8; 32], VALID_CIPHERSUITE[0], &policy),
+        Err(InitError::Seed(SeedError::Constant))
+    );
+    match PubParam::init_with_policy(&[0u8; 16], VALID_CIPHERSUITE[0], &policy) {
+        Err(InitError::Seed(SeedError::TooShort { min_len, len })) => {
+            assert_eq!((min_len, len), (32, 16))
+        }
+        _ => panic!("expected a short seed"),
+    }
+
+    // the errors of `init` come back with their message
+    assert_eq!(
+        PubParam::init_with_policy(&seed, 0xFF, &policy).unwrap_err(),
+        InitError::Init(ERR_CIPHERSUITE.to_owned())
+    );
+}
@@ -0,0 +1,93 @@
+// This module caches the prepared forms of the elements of the public
+// parameters that are fixed across signatures, i.e., `h` and `hlist`,
+// so that verifiers reuse the line functions of the Miller loop.
+// The cache is computed on the first call to `PubParam::prepared`.
+
+use crate::alloc::vec::Vec;
+use crate::engine::{Gt, PixelG1Prepared, PixelG2Affine, PixelG2Prepared};
+use crate::{GenericPubParam, PixelEngine};
+use pairing::{CurveAffine, Engine};
+
+/// The prepared forms of `h` and `h_0, ..., h_d`, along with the affine `g2`.
+pub struct PreparedParam<E: PixelEngine> {
+    g2: PixelG2Affine<E>,
+    h: PixelG1Prepared<E>,
+    hlist: Vec<PixelG1Prepared<E>>,
+}
+
+impl<E: PixelEngine> PreparedParam<E> {
+    pub(crate) fn new(pp: &GenericPubParam<E>) -> Self {
+        PreparedParam {
+            g2: *pp.g2_affine(),
+            h: pp.h_affine().prepare(),
+            hlist: pp.hlist_affine().iter().map(|e| e.prepare()).collect(),
+        }
+    }
+
+    /// Returns the `PixelG2` generator in affine form.
+    pub fn g2_affine(&self) -> &PixelG2Affine<E> {
+        &self.g2
+    }
+
+    /// Returns the prepared `h`.
+    pub fn h(&self) -> &PixelG1Prepared<E> {
+        &self.h
+    }
+
+    /// Returns the prepared `h_0, ..., h_d`.
+    pub fn hlist(&self) -> &[PixelG1Prepared<E>] {
+        &self.hlist
+    }
+
+    /// Computes the product of the Miller loops of `e(x_i, y_i)`,
+    /// where the `y_i` are typically taken from this struct.
+    pub fn multi_miller_loop(&self, terms: &[(&PixelG2Prepared<E>, &PixelG1Prepared<E>)]) -> Gt<E> {
+        E::pixel_miller_loop(terms)
+    }
+
+    /// Computes the product of the pairings `e(x_i, y_i)`, i.e., the
+    /// final exponentiation of `multi_miller_loop`.
+    /// It returns `None` if the final exponentiation fails.
+    pub fn multi_pairing(
+        &self,
+        terms: &[(&PixelG2Prepared<E>, &PixelG1Prepared<E>)],
+    ) -> Option<Gt<E>> {
+        <E::Engine as Engine>::final_exponentiation(&self.multi_miller_loop(terms))
+    }
+
+    /// Computes the Miller loop of `e(x, h)`.
+    pub fn miller_loop_h(&self, x: &PixelG2Prepared<E>) -> Gt<E> {
+        self.multi_miller_loop(&[(x, &self.h)])
+    }
+}
+
+/// The prepared elements are not printed.
+impl<E: PixelEngine> core::fmt::Debug for PreparedParam<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "PreparedParam {{ g2: {:?}, hlist: {} prepared elements }}",
+            self.g2,
+            self.hlist.len()
+        )
+    }
+}
+
+impl<E: PixelEngine> Clone for PreparedParam<E> {
+    fn clone(&self) -> Self {
+        PreparedParam {
+            g2: self.g2,
+            h: self.h.clone(),
+            hlist: self.hlist.clone(),
+        }
+    }
+}
+
+impl<E: PixelEngine> GenericPubParam<E> {
+    /// Returns the prepared forms of `h` and `h_0, ..., h_d`, and the affine `g2`.
+    /// They are computed on the first call and cached afterwards.
+    pub fn prepared(&self) -> &PreparedParam<E> {
+        self.prepared
+            .get_or_init(|| crate::alloc::boxed::Box::new(PreparedParam::new(self)))
+    }
+}
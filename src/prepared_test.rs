@@ -0,0 +1,72 @@
+// This module tests the cached prepared elements against fresh pairings.
+
+use crate::PubParam;
+use ff::Field;
+use pairing::bls12_381::{Bls12, Fq12};
+use pairing::{CurveAffine, CurveProjective, Engine};
+
+// e(g2, x) in the engine's order of the groups
+fn pairing_with_g2(pp: &PubParam, x: <Bls12 as crate::PixelEngine>::PixelG1) -> Fq12 {
+    #[cfg(not(feature = "no_swap"))]
+    {
+        Bls12::pairing(pp.g2(), x)
+    }
+    #[cfg(feature = "no_swap")]
+    {
+        Bls12::pairing(x, pp.g2())
+    }
+}
+
+#[test]
+fn test_prepared() {
+    let pp = PubParam::default();
+    let prep = pp.prepared();
+    // cached
+    assert!(std::ptr::eq(prep, pp.prepared()));
+    assert_eq!(prep.g2_affine(), pp.g2_affine());
+    assert_eq!(prep.hlist().len(), pp.depth() + 1);
+
+    // the pairings match fresh ones
+    let g2 = pp.g2_affine().prepare();
+    let res = prep.multi_pairing(&[(&g2, prep.h())]).unwrap();
+    assert_eq!(res, pairing_with_g2(&pp, pp.h()));
+    let res = Bls12::final_exponentiation(&prep.miller_loop_h(&g2)).unwrap();
+    assert_eq!(res, pairing_with_g2(&pp, pp.h()));
+    for i in [0, 1, pp.depth()].iter() {
+        let res = prep.multi_pairing(&[(&g2, &prep.hlist()[*i])]).unwrap();
+        assert_eq!(res, pairing_with_g2(&pp, pp.hlist()[*i]));
+    }
+
+    // e(g2, h_0) * e(-g2, h_0) = 1
+    let mut neg = pp.g2();
+    neg.negate();
+    let neg = neg.into_affine().prepare();
+    let res = prep
+        .multi_pairing(&[(&g2, &prep.hlist()[0]), (&neg, &prep.hlist()[0])])
+        .unwrap();
+    assert_eq!(res, Fq12::one());
+}
+
+#[test]
+fn test_prepared_clone_and_truncate() {
+    let pp = PubParam::default();
+    let g2 = pp.g2_affine().prepare();
+    let expect = pp.prepared().multi_pairing(&[(&g2, pp.prepared().h())]);
+
+    // the cache is carried over by clone
+    let pp_clone = pp.clone();
+    assert_eq!(
+        pp_clone
+            .prepared()
+            .multi_pairing(&[(&g2, pp_clone.prepared().h())]),
+        expect
+    );
+
+    // and recomputed for the truncated parameters
+    let pp_short = pp.truncate(4).unwrap();
+    assert_eq!(pp_short.prepared().hlist().len(), 5);
+    let res = pp_short
+        .prepared()
+        .multi_pairing(&[(&g2, &pp_short.prepared().hlist()[4])]);
+    assert_eq!(res, Some(pairing_with_g2(&pp, pp.hlist()[4])));
+}
@@ -0,0 +1,119 @@
+// This module publishes the public parameters as the `pixel_param`
+// python module via PyO3. Group elements are returned as bytes,
+// using the same encoding as the parameter blob, see `serdes::BlobPart`.
+
+use crate::serdes::BlobPart;
+use crate::PubParam;
+use pairing::bls12_381::Bls12;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+/// A wrapper of `PubParam` that is exported to python as `PubParam`.
+#[pyclass(name = "PubParam", module = "pixel_param")]
+#[derive(Clone, Debug)]
+pub struct PyPubParam(PubParam);
+
+#[pymethods]
+impl PyPubParam {
+    /// Generate the public parameter from a seed and a ciphersuite id.
+    /// Raises `ValueError` if the seed is too short or the ciphersuite is not supported.
+    #[staticmethod]
+    fn init(seed: &[u8], ciphersuite: u8) -> PyResult<Self> {
+        match PubParam::init(seed, ciphersuite) {
+            Ok(p) => Ok(PyPubParam(p)),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    }
+
+    /// Returns the default public parameter.
+    #[staticmethod]
+    #[pyo3(name = "default")]
+    fn default_param() -> Self {
+        PyPubParam(PubParam::default())
+    }
+
+    /// Convert the public parameter into a blob.
+    #[pyo3(signature = (compressed = true))]
+    fn to_bytes<'p>(&self, py: Python<'p>, compressed: bool) -> PyResult<&'p PyBytes> {
+        match self.0.to_bytes(compressed) {
+            Ok(p) => Ok(PyBytes::new(py, &p)),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    }
+
+    /// Convert a blob into a public parameter.
+    /// Raises `ValueError` if the blob is malformed.
+    #[staticmethod]
+    #[pyo3(signature = (data, compressed = true))]
+    fn from_bytes(data: &[u8], compressed: bool) -> PyResult<Self> {
+        match PubParam::from_bytes(data, compressed) {
+            Ok(p) => Ok(PyPubParam(p)),
+            Err(e) => Err(PyValueError::new_err(e)),
+        }
+    }
+
+    /// Returns the 32 bytes fingerprint of the public parameter.
+    fn fingerprint<'p>(&self, py: Python<'p>) -> &'p PyBytes {
+        PyBytes::new(py, &self.0.fingerprint())
+    }
+
+    /// Returns the depth of the time stamp.
+    fn depth(&self) -> usize {
+        self.0.depth()
+    }
+
+    /// Returns the ciphersuite id.
+    fn ciphersuite(&self) -> u8 {
+        self.0.ciphersuite()
+    }
+
+    /// Returns the encoded `PixelG2` generator.
+    #[pyo3(signature = (compressed = true))]
+    fn g2<'p>(&self, py: Python<'p>, compressed: bool) -> &'p PyBytes {
+        let mut buf = vec![];
+        BlobPart::<Bls12>::G2(self.0.g2_affine()).append_to(&mut buf, compressed);
+        PyBytes::new(py, &buf)
+    }
+
+    /// Returns the encoded `h` parameter.
+    #[pyo3(signature = (compressed = true))]
+    fn h<'p>(&self, py: Python<'p>, compressed: bool) -> &'p PyBytes {
+        let mut buf = vec![];
+        BlobPart::<Bls12>::G1(self.0.h_affine()).append_to(&mut buf, compressed);
+        PyBytes::new(py, &buf)
+    }
+
+    /// Returns the list of encoded `h_0, h_1, ..., h_d`.
+    #[pyo3(signature = (compressed = true))]
+    fn hlist<'p>(&self, py: Python<'p>, compressed: bool) -> Vec<&'p PyBytes> {
+        self.0
+            .hlist_affine()
+            .iter()
+            .map(|e| {
+                let mut buf = vec![];
+                BlobPart::<Bls12>::G1(e).append_to(&mut buf, compressed);
+                PyBytes::new(py, &buf)
+            })
+            .collect()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PubParam(ciphersuite={}, depth={})",
+            self.0.ciphersuite(),
+            self.0.depth()
+        )
+    }
+}
+
+/// The `pixel_param` python module.
+#[pymodule]
+fn pixel_param(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyPubParam>()?;
+    Ok(())
+}
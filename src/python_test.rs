@@ -0,0 +1,67 @@
+// This module tests the python bindings with an embedded interpreter.
+
+use crate::constants::SHA512_IV;
+use crate::python::PyPubParam;
+use crate::{PubParam, CONST_D, VALID_CIPHERSUITE};
+use pairing::CurveAffine;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+#[test]
+fn test_python_bindings() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let locals = PyDict::new(py);
+        locals
+            .set_item("PubParam", py.get_type::<PyPubParam>())
+            .unwrap();
+        locals
+            .set_item("seed", PyBytes::new(py, &SHA512_IV))
+            .unwrap();
+        locals.set_item("csid", VALID_CIPHERSUITE[0]).unwrap();
+        locals.set_item("depth", CONST_D).unwrap();
+        py.run(
+            r#"
+pp = PubParam.init(seed, csid)
+assert pp == PubParam.default()
+assert pp.depth() == depth
+assert pp.ciphersuite() == csid
+assert len(pp.hlist()) == depth + 1
+blob = pp.to_bytes()
+assert PubParam.from_bytes(blob) == pp
+assert PubParam.from_bytes(pp.to_bytes(False), False) == pp
+fingerprint = pp.fingerprint()
+g2 = pp.g2(False)
+h = pp.h()
+try:
+    PubParam.init(seed[:31], csid)
+    assert False
+except ValueError:
+    pass
+try:
+    PubParam.from_bytes(blob[:-1])
+    assert False
+except ValueError:
+    pass
+"#,
+            None,
+            Some(locals),
+        )
+        .unwrap();
+
+        // the python objects are the same as on the rust side
+        let pp = PubParam::default();
+        let get = |name: &str| -> Vec<u8> {
+            locals
+                .get_item(name)
+                .unwrap()
+                .extract::<&[u8]>()
+                .unwrap()
+                .to_vec()
+        };
+        assert_eq!(get("blob"), pp.to_bytes(true).unwrap());
+        assert_eq!(get("fingerprint"), pp.fingerprint().to_vec());
+        assert_eq!(get("g2"), pp.g2_affine().into_uncompressed().as_ref());
+        assert_eq!(get("h"), pp.h_affine().into_compressed().as_ref());
+    });
+}
@@ -0,0 +1,48 @@
+// This module generates public parameters from a random seed,
+// which is useful for tests that need many independent parameter sets.
+
+use crate::alloc::string::String;
+use crate::{GenericPubParam, PixelEngine};
+use rand_core::{CryptoRng, RngCore};
+
+impl<E: PixelEngine> GenericPubParam<E> {
+    /// This function draws a fresh 64 bytes seed from the rng, and
+    /// generates the public parameters with the seed and the ciphersuite.
+    /// It returns an error if the ciphersuite is not supported.
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R, ciphersuite: u8) -> Result<Self, String> {
+        Self::random_with_seed(rng, ciphersuite).map(|(pp, _)| pp)
+    }
+
+    /// Same as `random`, but also returns the seed, so that the
+    /// parameters can be reproduced later with `PubParam::init`.
+    pub fn random_with_seed<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        ciphersuite: u8,
+    ) -> Result<(Self, [u8; 64]), String> {
+        let mut seed = [0u8; 64];
+        rng.fill_bytes(&mut seed);
+        let pp = Self::init(&seed, ciphersuite)?;
+        Ok((pp, seed))
+    }
+}
+
+/// With feature `arbitrary`, downstream crates can property-test
+/// against arbitrary valid parameters, i.e., parameters generated from
+/// an arbitrary seed and an arbitrary valid ciphersuite.
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for crate::PubParam {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        use crate::constants::VALID_CIPHERSUITE;
+        use proptest::prelude::*;
+
+        (
+            any::<[u8; 32]>(),
+            proptest::sample::select(VALID_CIPHERSUITE.to_vec()),
+        )
+            .prop_map(|(seed, ciphersuite)| Self::init(&seed, ciphersuite).unwrap())
+            .boxed()
+    }
+}
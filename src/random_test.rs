@@ -0,0 +1,46 @@
+// This module tests the random parameter generation.
+
+use crate::constants::VALID_CIPHERSUITE;
+use crate::PubParam;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn test_random() {
+    let mut rng = StdRng::from_seed([0x42; 32]);
+    let pp1 = PubParam::random(&mut rng, VALID_CIPHERSUITE[0]).unwrap();
+    let pp2 = PubParam::random(&mut rng, VALID_CIPHERSUITE[0]).unwrap();
+    assert_ne!(pp1, pp2);
+
+    // the returned seed reproduces the parameters
+    let (pp, seed) = PubParam::random_with_seed(&mut rng, VALID_CIPHERSUITE[1]).unwrap();
+    let pp_seed = PubParam::init(&seed, VALID_CIPHERSUITE[1]).unwrap();
+    assert_eq!(pp, pp_seed);
+
+    // so does the rng seed
+    let mut rng = StdRng::from_seed([0x42; 32]);
+    let pp = PubParam::random(&mut rng, VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp, pp1);
+
+    // invalid ciphersuite
+    assert!(PubParam::random(&mut rng, 0xFF).is_err());
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary {
+    use crate::constants::VALID_CIPHERSUITE;
+    use crate::PubParam;
+    use proptest::prelude::*;
+
+    proptest! {
+        // each case generates a full parameter set, so keep the count low
+        #![proptest_config(ProptestConfig::with_cases(8))]
+        #[test]
+        fn test_arbitrary(pp in any::<PubParam>()) {
+            prop_assert!(VALID_CIPHERSUITE.contains(&pp.ciphersuite()));
+            let bytes = pp.to_bytes(true).unwrap();
+            let pp_recover = PubParam::from_bytes(&bytes, true).unwrap();
+            prop_assert_eq!(pp, pp_recover);
+        }
+    }
+}
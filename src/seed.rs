@@ -0,0 +1,71 @@
+// This module defines `SecretSeed`, a seed that is wiped from memory
+// when dropped, for ceremonies where the seed must remain private.
+//
+// With feature `zeroize`, `init` also wipes its intermediate secrets
+// on drop, i.e., on every path: the PRK, which `kdf::ParamHkdf` owns,
+// and the outputs of HKDF-Expand.
+// Copies made internally by `hmac`, `hkdf` and the hash functions
+// are out of our reach.
+
+use crate::alloc::vec::Vec;
+use zeroize::Zeroize;
+
+/// A seed that is zeroized on drop.
+/// The bytes are only accessible through `expose` and `as_ref`.
+pub struct SecretSeed(Vec<u8>);
+
+impl SecretSeed {
+    /// Wraps a seed; the vector is wiped when the `SecretSeed` is dropped.
+    pub fn new(seed: Vec<u8>) -> Self {
+        SecretSeed(seed)
+    }
+
+    /// Copies a seed into a `SecretSeed`;
+    /// the caller remains responsible for wiping the input.
+    pub fn from_slice(seed: &[u8]) -> Self {
+        SecretSeed(seed.to_vec())
+    }
+
+    /// Returns the bytes of the seed.
+    pub fn expose(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the length of the seed.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the seed is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<u8>> for SecretSeed {
+    fn from(seed: Vec<u8>) -> Self {
+        SecretSeed::new(seed)
+    }
+}
+
+impl Drop for SecretSeed {
+    fn drop(&mut self) {
+        // wipes the whole capacity, not only the length
+        self.0.zeroize();
+    }
+}
+
+/// The seed is not printed, to avoid leaking it into logs.
+impl core::fmt::Debug for SecretSeed {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "SecretSeed {{ len: {} }}", self.0.len())
+    }
+}
+
+/// Exposes the seed, so that it can be passed to `init` and its variants,
+/// e.g., `PubParam::init(&seed, ciphersuite)`.
+impl AsRef<[u8]> for SecretSeed {
+    fn as_ref(&self) -> &[u8] {
+        self.expose()
+    }
+}
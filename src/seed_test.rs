@@ -0,0 +1,127 @@
+// This module tests that the secrets are wiped.
+// A global allocator inspects the block of a watched secret when it
+// is freed, so that we can check it was cleared by `drop`.
+
+use crate::constants::{ERR_HKDF, SHA512_IV, VALID_CIPHERSUITE};
+use crate::kdf::{ParamHkdf, ParamKdf};
+use crate::seed::SecretSeed;
+use crate::PubParam;
+use sha2::Sha512;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+struct InspectingAllocator;
+
+// the address of the watched block, and the outcome of the inspection:
+// 0 = not freed yet, 1 = freed and cleared, 2 = freed with data left
+static WATCHED: AtomicUsize = AtomicUsize::new(0);
+static OUTCOME: AtomicUsize = AtomicUsize::new(0);
+// the tests share the watched block, so they run one at a time
+static LOCK: Mutex<()> = Mutex::new(());
+
+// watches the block at `ptr`, drops `secret`, and checks the block was cleared
+fn check_cleared_on_drop<T>(ptr: *const u8, secret: T) {
+    OUTCOME.store(0, Ordering::SeqCst);
+    WATCHED.store(ptr as usize, Ordering::SeqCst);
+    drop(secret);
+    assert_eq!(OUTCOME.load(Ordering::SeqCst), 1);
+}
+
+unsafe impl GlobalAlloc for InspectingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ptr as usize == WATCHED.load(Ordering::SeqCst) {
+            let block = std::slice::from_raw_parts(ptr, layout.size());
+            let outcome = if block.iter().all(|b| *b == 0) { 1 } else { 2 };
+            OUTCOME.store(outcome, Ordering::SeqCst);
+            WATCHED.store(0, Ordering::SeqCst);
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: InspectingAllocator = InspectingAllocator;
+
+#[test]
+fn test_secret_seed_cleared_on_drop() {
+    let _lock = LOCK.lock().unwrap();
+    let mut seed = Vec::with_capacity(128);
+    seed.extend_from_slice(&SHA512_IV);
+    let seed = SecretSeed::new(seed);
+    assert_eq!(seed.expose(), &SHA512_IV[..]);
+    assert_eq!(format!("{:?}", seed), "SecretSeed { len: 64 }");
+
+    // the seed gives the same parameters as the raw bytes
+    let pp = PubParam::init(&seed, VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp, PubParam::init_without_seed());
+
+    check_cleared_on_drop(seed.expose().as_ptr(), seed);
+}
+
+#[test]
+fn test_prk_cleared_on_drop() {
+    let _lock = LOCK.lock().unwrap();
+    let hk = <ParamHkdf<Sha512> as ParamKdf>::extract(b"salt", SHA512_IV.as_ref());
+    assert_eq!(hk.prk().len(), 64);
+    assert!(hk.prk().iter().any(|b| *b != 0));
+    assert_eq!(format!("{:?}", hk), "ParamHkdf { prk len: 64 }");
+    let mut output = [0u8; 32];
+    hk.expand(b"info", &mut output).unwrap();
+    assert_ne!(output, [0u8; 32]);
+
+    check_cleared_on_drop(hk.prk().as_ptr(), hk);
+}
+
+// a KDF that fills the output with ones, watches the output buffer,
+// and fails at the expansion `fail_at`
+struct WatchingKdf {
+    calls: Cell<usize>,
+    fail_at: usize,
+}
+
+impl ParamKdf for WatchingKdf {
+    fn extract(_salt: &[u8], _seed: &[u8]) -> Self {
+        WatchingKdf {
+            calls: Cell::new(0),
+            fail_at: usize::MAX,
+        }
+    }
+
+    fn expand(&self, _info: &[u8], output: &mut [u8]) -> Result<(), String> {
+        for b in output.iter_mut() {
+            *b = 0xFF;
+        }
+        OUTCOME.store(0, Ordering::SeqCst);
+        WATCHED.store(output.as_ptr() as usize, Ordering::SeqCst);
+        let calls = self.calls.get();
+        self.calls.set(calls + 1);
+        if calls == self.fail_at {
+            return Err(ERR_HKDF.to_owned());
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_expand_output_cleared_on_drop() {
+    let _lock = LOCK.lock().unwrap();
+    let mut kdf = WatchingKdf::extract(b"salt", SHA512_IV.as_ref());
+
+    // once the group element is derived
+    assert!(PubParam::derive_point(&kdf, b"info", VALID_CIPHERSUITE[0]).is_ok());
+    assert_eq!(OUTCOME.load(Ordering::SeqCst), 1);
+
+    // and when the expansion fails after writing the output
+    kdf.fail_at = kdf.calls.get();
+    assert_eq!(
+        PubParam::derive_point(&kdf, b"info", VALID_CIPHERSUITE[0]),
+        Err(ERR_HKDF.to_owned())
+    );
+    assert_eq!(OUTCOME.load(Ordering::SeqCst), 1);
+}
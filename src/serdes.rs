@@ -1,92 +1,275 @@
-use crate::constants::{CONST_D, ERR_CIPHERSUITE, VALID_CIPHERSUITE};
-use crate::{PixelG1, PixelG2, PubParam};
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::constants::{
+    CONST_D, ERR_CIPHERSUITE, ERR_DEPTH, ERR_GROUP_ELEMENT, ERR_SERIAL_LEN, VALID_CIPHERSUITE,
+};
+use crate::{GenericPubParam, PixelEngine};
+#[cfg(feature = "std")]
 pub use pairing::serdes::SerDes;
-use pairing::CurveProjective;
+use pairing::{CurveAffine, CurveProjective, EncodedPoint};
+#[cfg(feature = "std")]
 use std::io::{Error, ErrorKind, Read, Result, Write};
 
 /// The length of the public parameter, in the compressed format.
+#[cfg(not(feature = "no_swap"))]
 pub const PP_LEN_COMPRESSED: usize = 3314;
 /// The length of the public parameter, in the uncompressed format.
+#[cfg(not(feature = "no_swap"))]
 pub const PP_LEN_UNCOMPRESSED: usize = 6626;
+/// The length of the public parameter, in the compressed format.
+#[cfg(feature = "no_swap")]
+pub const PP_LEN_COMPRESSED: usize = 1730;
+/// The length of the public parameter, in the uncompressed format.
+#[cfg(feature = "no_swap")]
+pub const PP_LEN_UNCOMPRESSED: usize = 3458;
 
 type Compressed = bool;
 
-impl SerDes for PubParam {
+impl<E: PixelEngine> GenericPubParam<E> {
     /// Convert a public parameter into a blob:
     ///
     /// `|ciphersuite id| depth | g2 | h | hlist |` => bytes
     ///
-    /// Returns an error if ciphersuite id is invalid or serialization fails.
-    fn serialize<W: Write>(&self, writer: &mut W, compressed: Compressed) -> Result<()> {
-        // check the cipher suite id
+    /// Returns an error if ciphersuite id is invalid.
+    /// This function does not require `std`.
+    pub fn to_bytes(&self, compressed: Compressed) -> core::result::Result<Vec<u8>, String> {
+        self.check_ciphersuite()?;
+        Ok(self.encode(compressed))
+    }
+
+    /// Check that the ciphersuite id is valid, before serialization.
+    pub(crate) fn check_ciphersuite(&self) -> core::result::Result<(), String> {
         if !VALID_CIPHERSUITE.contains(&self.ciphersuite()) {
-            return Err(Error::new(ErrorKind::InvalidData, ERR_CIPHERSUITE));
+            return Err(ERR_CIPHERSUITE.to_owned());
         }
-        // first byte is the ciphersuite id
-        let mut buf: Vec<u8> = vec![self.ciphersuite()];
-
-        // Second byte is the time depth
-        buf.push(self.depth() as u8);
+        Ok(())
+    }
 
-        // serialize g2
-        self.g2().serialize(&mut buf, compressed)?;
+    /// Encode the public parameter without checking the ciphersuite id.
+    pub(crate) fn encode(&self, compressed: Compressed) -> Vec<u8> {
+        let mut buf: Vec<u8> = Vec::with_capacity(self.size(compressed));
+        for part in self.blob_parts() {
+            part.append_to(&mut buf, compressed);
+        }
+        buf
+    }
 
-        // serialize h
-        self.h().serialize(&mut buf, compressed)?;
+    /// Returns the parts of the blob in order, i.e.,
+    /// the ciphersuite id and the time depth, then g2, h and hlist
+    /// from their cached affine forms.
+    /// This is the sequence written by `to_bytes`, `SerDes::serialize`
+    /// and `write_async`.
+    pub(crate) fn blob_parts(&self) -> impl Iterator<Item = BlobPart<'_, E>> {
+        core::iter::once(BlobPart::Header([self.ciphersuite(), self.depth() as u8]))
+            .chain(core::iter::once(BlobPart::G2(self.g2_affine())))
+            .chain(core::iter::once(BlobPart::G1(self.h_affine())))
+            .chain(self.hlist_affine().iter().map(BlobPart::G1))
+    }
 
-        // serialize hlist
-        for e in self.hlist().iter() {
-            e.serialize(&mut buf, compressed)?;
-        }
-        // format the output
-        writer.write_all(&buf)?;
-        Ok(())
+    /// Checks the ciphersuite id, and returns the parts of the blob to be written
+    /// to a stream. Returns an `InvalidData` error if the ciphersuite id is invalid.
+    #[cfg(feature = "std")]
+    pub(crate) fn parts_to_write(&self) -> Result<impl Iterator<Item = BlobPart<'_, E>>> {
+        self.check_ciphersuite().map_err(invalid_data)?;
+        Ok(self.blob_parts())
     }
 
     /// Convert a blob into a public parameter:
     ///
     /// bytes => `|ciphersuite id| depth | g2 | h | hlist |`
     ///
-    /// Returns an error if deserialization fails.
-    fn deserialize<R: Read>(reader: &mut R, comp: Compressed) -> Result<Self> {
-        // constants stores id and the depth
-        let mut constants: [u8; 2] = [0u8; 2];
-
-        reader.read_exact(&mut constants)?;
-        // constants[1] stores depth d
-        let depth = constants[1] as usize;
-        assert_eq!(
-            depth, CONST_D,
-            "Deserialization err: the depth doesn't match!"
-        );
-
-        // check the ciphersuite id in the blob
-        if !VALID_CIPHERSUITE.contains(&constants[0]) {
-            return Err(Error::new(ErrorKind::InvalidData, ERR_CIPHERSUITE));
+    /// Returns an error if the blob is malformed: wrong length,
+    /// depth greater than `CONST_D`, invalid ciphersuite id or invalid group elements.
+    /// This function does not require `std`.
+    pub fn from_bytes(bytes: &[u8], compressed: Compressed) -> core::result::Result<Self, String> {
+        if bytes.len() < 2 {
+            return Err(ERR_SERIAL_LEN.to_owned());
+        }
+        // check the ciphersuite id and the depth in the blob
+        if bytes.len() != check_header::<E>([bytes[0], bytes[1]], compressed)? {
+            return Err(ERR_SERIAL_LEN.to_owned());
         }
+        let depth = bytes[1] as usize;
+        let mut rest = &bytes[2..];
 
         // read into g2
-        let g2 = PixelG2::deserialize(reader, comp)?;
+        let g2: E::PixelG2 = read_point(&mut rest, compressed)?;
 
         // read into h
-        let h = PixelG1::deserialize(reader, comp)?;
+        let h: E::PixelG1 = read_point(&mut rest, compressed)?;
 
         // read into hlist
-        let mut hlist: Vec<PixelG1> = vec![];
-        for _i in 0..=depth {
-            let tmp = PixelG1::deserialize(reader, comp)?;
-
-            hlist.push(tmp);
+        let mut hlist_array: [E::PixelG1; CONST_D + 1] = [E::PixelG1::zero(); CONST_D + 1];
+        for e in hlist_array.iter_mut().take(depth + 1) {
+            *e = read_point(&mut rest, compressed)?;
         }
-        let mut hlist_array: [PixelG1; CONST_D + 1] = [PixelG1::zero(); CONST_D + 1];
-        hlist_array.copy_from_slice(&hlist);
+
         // finished
-        Ok(PubParam {
+        Ok(GenericPubParam::from_parts(
             depth,
-            ciphersuite: constants[0],
+            bytes[0],
             g2,
             h,
-            hlist: hlist_array,
-        })
+            hlist_array,
+        ))
+    }
+}
+
+/// Checks the first two bytes of a blob, i.e., the ciphersuite id and the depth,
+/// and returns the length of the whole blob.
+/// Returns an error if the depth is greater than `CONST_D`,
+/// or if the ciphersuite id is invalid.
+pub(crate) fn check_header<E: PixelEngine>(
+    header: [u8; 2],
+    compressed: Compressed,
+) -> core::result::Result<usize, String> {
+    // header[1] stores depth d
+    let depth = header[1] as usize;
+    if depth > CONST_D {
+        return Err(ERR_DEPTH.to_owned());
+    }
+    // check the ciphersuite id in the blob
+    if !VALID_CIPHERSUITE.contains(&header[0]) {
+        return Err(ERR_CIPHERSUITE.to_owned());
+    }
+    Ok(serialized_len::<E>(depth, compressed))
+}
+
+/// Checks the first two bytes of a blob read from a stream, and returns
+/// a buffer for the whole blob that starts with them;
+/// the caller then reads the rest of the blob into `buf[2..]`.
+/// Returns an `InvalidData` error under the same conditions as `check_header`.
+#[cfg(feature = "std")]
+pub(crate) fn blob_buffer<E: PixelEngine>(
+    header: [u8; 2],
+    compressed: Compressed,
+) -> Result<Vec<u8>> {
+    let len = check_header::<E>(header, compressed).map_err(invalid_data)?;
+    let mut buf = header.to_vec();
+    buf.resize(len, 0);
+    Ok(buf)
+}
+
+/// Decodes a blob read from a stream.
+/// Returns an `InvalidData` error under the same conditions as `from_bytes`.
+#[cfg(feature = "std")]
+pub(crate) fn decode_blob<E: PixelEngine>(
+    buf: &[u8],
+    compressed: Compressed,
+) -> Result<GenericPubParam<E>> {
+    GenericPubParam::from_bytes(buf, compressed).map_err(invalid_data)
+}
+
+#[cfg(feature = "std")]
+fn invalid_data(e: String) -> Error {
+    Error::new(ErrorKind::InvalidData, e)
+}
+
+/// A part of the blob `|ciphersuite id| depth | g2 | h | hlist |`:
+/// the first two bytes, or a group element in affine form.
+pub(crate) enum BlobPart<'a, E: PixelEngine> {
+    Header([u8; 2]),
+    G2(&'a <E::PixelG2 as CurveProjective>::Affine),
+    G1(&'a <E::PixelG1 as CurveProjective>::Affine),
+}
+
+impl<'a, E: PixelEngine> BlobPart<'a, E> {
+    /// Append the encoding of the part to the buffer.
+    pub(crate) fn append_to(&self, buf: &mut Vec<u8>, compressed: Compressed) {
+        match self {
+            BlobPart::Header(header) => buf.extend_from_slice(header),
+            BlobPart::G2(p) => append_affine(buf, *p, compressed),
+            BlobPart::G1(p) => append_affine(buf, *p, compressed),
+        }
+    }
+}
+
+/// Returns the length of a blob with a given depth:
+/// 2 bytes for ciphersuite and depth, followed by a `PixelG2`
+/// element and `depth + 2` `PixelG1` elements of the engine.
+pub(crate) fn serialized_len<E: PixelEngine>(depth: usize, compressed: Compressed) -> usize {
+    2 + point_len::<E::PixelG2>(compressed) + (depth + 2) * point_len::<E::PixelG1>(compressed)
+}
+
+/// Returns the size of an encoded group element.
+pub(crate) fn point_len<G: CurveProjective>(compressed: Compressed) -> usize {
+    if compressed {
+        <<G::Affine as CurveAffine>::Compressed as EncodedPoint>::size()
+    } else {
+        <<G::Affine as CurveAffine>::Uncompressed as EncodedPoint>::size()
+    }
+}
+
+/// Append the zcash encoding of an affine group element to the buffer.
+fn append_affine<A: CurveAffine>(buf: &mut Vec<u8>, p: &A, compressed: Compressed) {
+    if compressed {
+        buf.extend_from_slice(p.into_compressed().as_ref());
+    } else {
+        buf.extend_from_slice(p.into_uncompressed().as_ref());
+    }
+}
+
+/// Decode a group element from the head of the slice, and
+/// advance the slice past it.
+pub(crate) fn read_point<G: CurveProjective>(
+    bytes: &mut &[u8],
+    compressed: Compressed,
+) -> core::result::Result<G, String> {
+    let len = point_len::<G>(compressed);
+    if bytes.len() < len {
+        return Err(ERR_SERIAL_LEN.to_owned());
+    }
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    let affine = if compressed {
+        let mut encoded = <G::Affine as CurveAffine>::Compressed::empty();
+        encoded.as_mut().copy_from_slice(head);
+        encoded.into_affine()
+    } else {
+        let mut encoded = <G::Affine as CurveAffine>::Uncompressed::empty();
+        encoded.as_mut().copy_from_slice(head);
+        encoded.into_affine()
+    };
+    match affine {
+        Ok(p) => Ok(p.into_projective()),
+        Err(_) => Err(ERR_GROUP_ELEMENT.to_owned()),
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: PixelEngine> SerDes for GenericPubParam<E> {
+    /// Convert a public parameter into a blob:
+    ///
+    /// `|ciphersuite id| depth | g2 | h | hlist |` => bytes
+    ///
+    /// Returns an error if ciphersuite id is invalid or serialization fails.
+    /// The elements are streamed to the writer one by one from their
+    /// cached affine forms, without buffering the whole blob.
+    fn serialize<W: Write>(&self, writer: &mut W, compressed: Compressed) -> Result<()> {
+        let mut buf = Vec::new();
+        for part in self.parts_to_write()? {
+            buf.clear();
+            part.append_to(&mut buf, compressed);
+            writer.write_all(&buf)?;
+        }
+        Ok(())
+    }
+
+    /// Convert a blob into a public parameter:
+    ///
+    /// bytes => `|ciphersuite id| depth | g2 | h | hlist |`
+    ///
+    /// Returns an error if deserialization fails.
+    fn deserialize<R: Read>(reader: &mut R, comp: Compressed) -> Result<Self> {
+        // constants stores id and the depth
+        let mut constants: [u8; 2] = [0u8; 2];
+        reader.read_exact(&mut constants)?;
+
+        // check the ciphersuite id and the depth,
+        // then read the remaining of the blob, and decode the whole blob
+        let mut buf = blob_buffer::<E>(constants, comp)?;
+        reader.read_exact(&mut buf[2..])?;
+        decode_blob(&buf, comp)
     }
 }
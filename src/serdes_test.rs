@@ -37,3 +37,46 @@ fn test_param_serialization() {
     // makes sure that the keys match
     assert_eq!(pp, pp_recover);
 }
+
+#[test]
+fn test_param_bytes() {
+    let pp = PubParam::init_without_seed();
+
+    for compressed in [true, false].iter() {
+        // the slice based encoding matches the `SerDes` encoding
+        let bytes = pp.to_bytes(*compressed).unwrap();
+        let mut buf: Vec<u8> = vec![];
+        assert!(pp.serialize(&mut buf, *compressed).is_ok());
+        assert_eq!(bytes, buf);
+
+        // and it decodes into the same parameter
+        let pp_recover = PubParam::from_bytes(&bytes, *compressed).unwrap();
+        assert_eq!(pp, pp_recover);
+
+        // a truncated blob is rejected
+        assert!(PubParam::from_bytes(&bytes[..bytes.len() - 1], *compressed).is_err());
+        // so is a blob with a wrong depth
+        let mut bad_depth = bytes.clone();
+        bad_depth[1] += 1;
+        assert!(PubParam::from_bytes(&bad_depth, *compressed).is_err());
+    }
+}
+
+#[test]
+fn test_param_group_assignment() {
+    use crate::NO_SWAP_FLAG;
+    let pp = PubParam::init_without_seed();
+
+    // the ciphersuite id records the group assignment
+    #[cfg(feature = "no_swap")]
+    assert_eq!(pp.ciphersuite() & NO_SWAP_FLAG, NO_SWAP_FLAG);
+    #[cfg(not(feature = "no_swap"))]
+    assert_eq!(pp.ciphersuite() & NO_SWAP_FLAG, 0);
+
+    // a blob claiming the other assignment is rejected
+    let mut bytes = pp.to_bytes(true).unwrap();
+    bytes[0] ^= NO_SWAP_FLAG;
+    assert!(PubParam::from_bytes(&bytes, true).is_err());
+    assert_eq!(pp.size(true), PP_LEN_COMPRESSED);
+    assert_eq!(pp.size(false), PP_LEN_UNCOMPRESSED);
+}
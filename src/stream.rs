@@ -0,0 +1,99 @@
+// This module implements an incremental decoder of the parameter blob,
+// for readers that receive the blob in fragments, e.g., from the network.
+// The chunks are buffered until the blob is complete; the ciphersuite id
+// and the depth are checked as soon as the first two bytes arrive, with
+// the same checks as `from_bytes`, so the buffer never exceeds the
+// length of a valid blob.
+
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::serdes::check_header;
+use crate::{GenericPubParam, PixelEngine};
+use core::marker::PhantomData;
+use pairing::bls12_381::Bls12;
+
+/// The outcome of feeding a chunk to a `Decoder`.
+#[derive(Debug)]
+pub enum Progress<E: PixelEngine = Bls12> {
+    /// The blob is incomplete; at least this many more bytes are needed.
+    NeedMore(usize),
+    /// The blob is complete. The second element is the number of bytes
+    /// of the last chunk that belong to the blob; the rest is left unread.
+    Done(GenericPubParam<E>, usize),
+}
+
+/// An incremental decoder of BLS12-381 parameter blobs.
+pub type Decoder = GenericDecoder<Bls12>;
+
+/// An incremental decoder of the blob `|ciphersuite id| depth | g2 | h | hlist |`,
+/// over the pairing engine `E`.
+/// Once a blob is decoded, the decoder is ready for the next one.
+#[derive(Clone, Debug)]
+pub struct GenericDecoder<E: PixelEngine> {
+    compressed: bool,
+    buf: Vec<u8>,
+    // the length of the blob, once the header is known
+    len: Option<usize>,
+    engine: PhantomData<E>,
+}
+
+impl<E: PixelEngine> GenericDecoder<E> {
+    /// Starts decoding a blob in the given format.
+    pub fn new(compressed: bool) -> Self {
+        GenericDecoder {
+            compressed,
+            buf: Vec::new(),
+            len: None,
+            engine: PhantomData,
+        }
+    }
+
+    /// Feeds a chunk of the blob to the decoder.
+    /// Returns an error if the ciphersuite id or the depth is invalid,
+    /// or if the complete blob fails to decode; the decoder is then reset.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<Progress<E>, String> {
+        let mut used = 0;
+        // the header
+        if self.len.is_none() {
+            let n = (2 - self.buf.len()).min(chunk.len());
+            self.buf.extend_from_slice(&chunk[..n]);
+            used += n;
+            if self.buf.len() < 2 {
+                return Ok(Progress::NeedMore(2 - self.buf.len()));
+            }
+            match check_header::<E>([self.buf[0], self.buf[1]], self.compressed) {
+                Ok(len) => {
+                    self.buf.reserve_exact(len - 2);
+                    self.len = Some(len);
+                }
+                Err(e) => {
+                    self.reset();
+                    return Err(e);
+                }
+            }
+        }
+
+        // the remaining of the blob
+        let len = self.len.unwrap_or(0);
+        let n = (len - self.buf.len()).min(chunk.len() - used);
+        self.buf.extend_from_slice(&chunk[used..used + n]);
+        used += n;
+        if self.buf.len() < len {
+            return Ok(Progress::NeedMore(len - self.buf.len()));
+        }
+        let res = GenericPubParam::from_bytes(&self.buf, self.compressed);
+        self.reset();
+        Ok(Progress::Done(res?, used))
+    }
+
+    /// Returns the number of bytes buffered so far.
+    pub fn buffered(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Discards the buffered bytes.
+    pub fn reset(&mut self) {
+        self.buf = Vec::new();
+        self.len = None;
+    }
+}
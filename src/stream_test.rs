@@ -0,0 +1,102 @@
+// This module tests the incremental decoder and the streamed serialization.
+
+use crate::stream::{Decoder, Progress};
+use crate::PubParam;
+
+// feeds the blob in chunks of a given size, and returns the decoded parameters
+fn decode_in_chunks(bytes: &[u8], compressed: bool, size: usize) -> PubParam {
+    let mut decoder = Decoder::new(compressed);
+    let mut chunks = bytes.chunks(size).peekable();
+    while let Some(chunk) = chunks.next() {
+        match decoder.push(chunk).unwrap() {
+            Progress::NeedMore(n) => {
+                assert!(chunks.peek().is_some());
+                assert!(n <= bytes.len() - decoder.buffered());
+            }
+            Progress::Done(pp, used) => {
+                assert!(chunks.peek().is_none());
+                assert_eq!(used, chunk.len());
+                return pp;
+            }
+        }
+    }
+    panic!("the blob is incomplete");
+}
+
+#[test]
+fn test_decoder() {
+    let pp = PubParam::default();
+    for &compressed in [true, false].iter() {
+        let bytes = pp.to_bytes(compressed).unwrap();
+        for &size in [1, 2, 3, 48, 1500, bytes.len()].iter() {
+            assert_eq!(decode_in_chunks(&bytes, compressed, size), pp);
+        }
+    }
+
+    // the decoder stops at the end of the blob, and is ready for the next one
+    let bytes = pp.to_bytes(true).unwrap();
+    let short = pp.truncate(2).unwrap();
+    let mut stream = bytes.clone();
+    stream.extend_from_slice(&short.to_bytes(true).unwrap());
+    let mut decoder = Decoder::new(true);
+    match decoder.push(&stream).unwrap() {
+        Progress::Done(p, used) => {
+            assert_eq!(p, pp);
+            assert_eq!(used, bytes.len());
+        }
+        Progress::NeedMore(_) => panic!("the blob is complete"),
+    }
+    match decoder.push(&stream[bytes.len()..]).unwrap() {
+        Progress::Done(p, _) => assert_eq!(p, short),
+        Progress::NeedMore(_) => panic!("the blob is complete"),
+    }
+    assert_eq!(decoder.buffered(), 0);
+}
+
+#[test]
+fn test_decoder_errors() {
+    let pp = PubParam::default();
+    let bytes = pp.to_bytes(true).unwrap();
+
+    // the header is rejected as soon as it arrives
+    let mut decoder = Decoder::new(true);
+    assert!(decoder.push(&[0xFF]).is_ok());
+    assert!(decoder.push(&[bytes[1]]).is_err());
+    assert_eq!(decoder.buffered(), 0);
+    assert!(decoder.push(&[bytes[0], 0xFF]).is_err());
+
+    // an invalid element is reported once the blob is complete
+    let mut bad = bytes.clone();
+    bad[10] ^= 0xFF;
+    let mut decoder = Decoder::new(true);
+    assert!(decoder.push(&bad[..100]).is_ok());
+    assert!(decoder.push(&bad[100..]).is_err());
+    // and the decoder recovers
+    assert!(decoder.push(&bytes).is_ok());
+}
+
+#[test]
+fn test_streamed_serialize() {
+    use crate::SerDes;
+    use std::io::Write;
+
+    // a writer that rejects large writes, to check that no whole blob is written at once
+    struct SmallWrites(Vec<u8>);
+    impl Write for SmallWrites {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            assert!(buf.len() <= 2 * 96 + 2);
+            self.0.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let pp = PubParam::default();
+    for &compressed in [true, false].iter() {
+        let mut writer = SmallWrites(vec![]);
+        pp.serialize(&mut writer, compressed).unwrap();
+        assert_eq!(writer.0, pp.to_bytes(compressed).unwrap());
+    }
+}
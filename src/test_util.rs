@@ -0,0 +1,16 @@
+// This module holds the helpers shared by the tests.
+
+use std::path::PathBuf;
+
+// writes a blob into a fresh file of the temporary directory;
+// `module` keeps the files of the test modules apart
+pub(crate) fn write_file(module: &str, name: &str, bytes: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "pixel_param_{}_{}_{}",
+        module,
+        std::process::id(),
+        name
+    ));
+    std::fs::write(&path, bytes).unwrap();
+    path
+}
@@ -0,0 +1,168 @@
+// This module maps the time stamps to the nodes of the time tree.
+//
+// The time tree of depth `D` is a binary tree with `2^D - 1` nodes,
+// numbered `1, ..., 2^D - 1` in pre-order. A node is given by its path
+// from the root, a vector `(t_1, ..., t_l)` with `l <= D - 1`, where
+// `t_i = 1` means the left child and `t_i = 2` the right child:
+// * the root is the empty vector, i.e., time stamp 1
+// * the left child of a node at height `h` has time stamp `t + 1`
+// * the right child of a node at height `h` has time stamp `t + 2^(h-1)`
+// where the root has height `D`.
+//
+// The successor set of a time stamp `t` is the minimal set of nodes
+// whose subtrees cover `[t, 2^D - 1]`: the node itself, and the right
+// siblings of the nodes on its path that are left children.
+// Pixel key updates are defined in terms of these operations.
+
+use crate::alloc::borrow::ToOwned;
+use crate::alloc::string::String;
+use crate::alloc::vec::Vec;
+use crate::constants::{CONST_D, ERR_TIME_DEPTH, ERR_TIME_PATH, ERR_TIME_STAMP};
+use crate::{GenericPubParam, PixelEngine};
+
+/// A time stamp, between `1` and `2^D - 1` for a tree of depth `D`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeStamp(pub u64);
+
+/// The path of a node in the time tree of a given depth.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TimeVec {
+    depth: usize,
+    path: Vec<u8>,
+}
+
+/// Returns the maximum time stamp of a tree, i.e., `2^depth - 1`,
+/// or an error if the depth is not in `[1, CONST_D]`.
+pub fn max_time_stamp(depth: usize) -> Result<TimeStamp, String> {
+    if depth == 0 || depth > CONST_D {
+        return Err(ERR_TIME_DEPTH.to_owned());
+    }
+    Ok(TimeStamp((1u64 << depth) - 1))
+}
+
+impl TimeStamp {
+    /// Returns the path of the time stamp in a tree of the given depth.
+    /// It returns an error if the depth is not in `[1, CONST_D]`,
+    /// or if the time stamp is not in `[1, 2^depth - 1]`.
+    pub fn to_path(self, depth: usize) -> Result<TimeVec, String> {
+        if self.0 == 0 || self > max_time_stamp(depth)? {
+            return Err(ERR_TIME_STAMP.to_owned());
+        }
+        let mut path = Vec::new();
+        let mut t = self.0;
+        let mut height = depth;
+        while t != 1 {
+            // the left subtree holds the next 2^(h-1) - 1 time stamps
+            if t <= 1 << (height - 1) {
+                path.push(1);
+                t -= 1;
+            } else {
+                path.push(2);
+                t -= 1 << (height - 1);
+            }
+            height -= 1;
+        }
+        Ok(TimeVec { depth, path })
+    }
+
+    /// Returns the time stamp of a path.
+    pub fn from_path(path: &TimeVec) -> Self {
+        path.time()
+    }
+}
+
+impl TimeVec {
+    /// Builds a path in a tree of the given depth.
+    /// It returns an error if the depth is not in `[1, CONST_D]`,
+    /// if the path is longer than `depth - 1`,
+    /// or if an entry of the path is neither 1 nor 2.
+    pub fn new(path: &[u8], depth: usize) -> Result<Self, String> {
+        max_time_stamp(depth)?;
+        if path.len() >= depth || path.iter().any(|e| *e != 1 && *e != 2) {
+            return Err(ERR_TIME_PATH.to_owned());
+        }
+        Ok(TimeVec {
+            depth,
+            path: path.to_vec(),
+        })
+    }
+
+    /// Returns the depth of the tree.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the path, i.e., the entries `t_1, ..., t_l` in `{1, 2}`.
+    pub fn path(&self) -> &[u8] {
+        &self.path
+    }
+
+    /// Returns the time stamp of the node.
+    pub fn time(&self) -> TimeStamp {
+        let mut t = 1u64;
+        for (i, e) in self.path.iter().enumerate() {
+            // the height of the parent is depth - i
+            if *e == 1 {
+                t += 1;
+            } else {
+                t += 1 << (self.depth - i - 1);
+            }
+        }
+        TimeStamp(t)
+    }
+
+    /// Returns true if the node is an ancestor of `other`,
+    /// i.e., its path is a prefix of the path of `other`.
+    /// A node is its own ancestor; nodes of distinct trees are unrelated.
+    pub fn is_ancestor(&self, other: &TimeVec) -> bool {
+        self.depth == other.depth && other.path.starts_with(&self.path)
+    }
+
+    /// Returns the successor set of the node, i.e., the minimal set of nodes
+    /// whose subtrees cover `[t, 2^D - 1]`, sorted by time stamp.
+    pub fn successor_set(&self) -> Vec<TimeVec> {
+        let mut res = Vec::with_capacity(self.path.len() + 1);
+        res.push(self.clone());
+        // the right siblings, from the deepest to the root
+        for (i, e) in self.path.iter().enumerate().rev() {
+            if *e == 1 {
+                let mut path = self.path[..i].to_vec();
+                path.push(2);
+                res.push(TimeVec {
+                    depth: self.depth,
+                    path,
+                });
+            }
+        }
+        res
+    }
+}
+
+/// Returns the successor set of a time stamp in a tree of the given depth,
+/// i.e., the minimal set of nodes whose subtrees cover `[t, 2^depth - 1]`.
+/// It returns an error under the same conditions as `TimeStamp::to_path`.
+pub fn successor_set(t: TimeStamp, depth: usize) -> Result<Vec<TimeVec>, String> {
+    Ok(t.to_path(depth)?.successor_set())
+}
+
+impl<E: PixelEngine> GenericPubParam<E> {
+    /// Returns the path of a time stamp in the time tree of the parameters.
+    /// It returns an error if the time stamp is not in `[1, 2^depth - 1]`.
+    pub fn time_to_path(&self, t: TimeStamp) -> Result<TimeVec, String> {
+        t.to_path(self.depth)
+    }
+
+    /// Returns the successor set of a time stamp in the time tree of the parameters.
+    /// It returns an error if the time stamp is not in `[1, 2^depth - 1]`.
+    pub fn successor_set(&self, t: TimeStamp) -> Result<Vec<TimeVec>, String> {
+        successor_set(t, self.depth)
+    }
+
+    /// Checks that a path belongs to the time tree of the parameters.
+    pub fn check_path(&self, path: &TimeVec) -> Result<(), String> {
+        if path.depth != self.depth {
+            return Err(ERR_TIME_DEPTH.to_owned());
+        }
+        Ok(())
+    }
+}
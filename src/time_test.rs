@@ -0,0 +1,109 @@
+// This module tests the mapping between time stamps and tree paths.
+
+use crate::time::{max_time_stamp, successor_set, TimeStamp, TimeVec};
+use crate::{PubParam, CONST_D};
+
+#[test]
+fn test_time_path() {
+    // the tree of depth 4 in pre-order
+    let expected: [&[u8]; 15] = [
+        &[],
+        &[1],
+        &[1, 1],
+        &[1, 1, 1],
+        &[1, 1, 2],
+        &[1, 2],
+        &[1, 2, 1],
+        &[1, 2, 2],
+        &[2],
+        &[2, 1],
+        &[2, 1, 1],
+        &[2, 1, 2],
+        &[2, 2],
+        &[2, 2, 1],
+        &[2, 2, 2],
+    ];
+    for (i, path) in expected.iter().enumerate() {
+        let t = TimeStamp(i as u64 + 1);
+        let tv = t.to_path(4).unwrap();
+        assert_eq!(tv.path(), *path);
+        assert_eq!(tv, TimeVec::new(path, 4).unwrap());
+        assert_eq!(TimeStamp::from_path(&tv), t);
+    }
+
+    // round trip over the full tree
+    for &t in [1u64, 2, 3, 1 << 31, (1 << 32) - 2, (1 << 32) - 1].iter() {
+        let tv = TimeStamp(t).to_path(CONST_D).unwrap();
+        assert!(tv.path().len() < CONST_D);
+        assert_eq!(tv.time(), TimeStamp(t));
+    }
+    assert_eq!(
+        TimeStamp((1 << 32) - 1).to_path(CONST_D).unwrap().path(),
+        &[2u8; CONST_D - 1][..]
+    );
+
+    // out of bounds
+    assert_eq!(max_time_stamp(4), Ok(TimeStamp(15)));
+    assert!(TimeStamp(0).to_path(4).is_err());
+    assert!(TimeStamp(16).to_path(4).is_err());
+    assert!(TimeStamp(1).to_path(0).is_err());
+    assert!(TimeStamp(1).to_path(CONST_D + 1).is_err());
+    assert!(TimeVec::new(&[1, 2, 1, 2], 4).is_err());
+    assert!(TimeVec::new(&[1, 3], 4).is_err());
+}
+
+#[test]
+fn test_time_ancestor() {
+    let root = TimeStamp(1).to_path(4).unwrap();
+    let node = TimeVec::new(&[1, 2], 4).unwrap();
+    let leaf = TimeVec::new(&[1, 2, 1], 4).unwrap();
+    let other = TimeVec::new(&[2, 1], 4).unwrap();
+    assert!(root.is_ancestor(&leaf));
+    assert!(node.is_ancestor(&leaf));
+    assert!(node.is_ancestor(&node));
+    assert!(!leaf.is_ancestor(&node));
+    assert!(!node.is_ancestor(&other));
+    // distinct trees
+    assert!(!TimeVec::new(&[1], 5).unwrap().is_ancestor(&leaf));
+}
+
+#[test]
+fn test_successor_set() {
+    let depth = 5;
+    let max = max_time_stamp(depth).unwrap().0;
+    for t in 1..=max {
+        let set = successor_set(TimeStamp(t), depth).unwrap();
+        // the subtrees partition [t, max]
+        let mut covered = vec![];
+        for s in 1..=max {
+            let tv = TimeStamp(s).to_path(depth).unwrap();
+            let count = set.iter().filter(|e| e.is_ancestor(&tv)).count();
+            assert_eq!(count, if s >= t { 1 } else { 0 });
+            if count == 1 {
+                covered.push(s);
+            }
+        }
+        assert_eq!(covered.len() as u64, max - t + 1);
+        // sorted, starting with t itself
+        assert_eq!(set[0].time(), TimeStamp(t));
+        assert!(set.windows(2).all(|w| w[0].time() < w[1].time()));
+    }
+    let set = successor_set(TimeStamp(4), 4).unwrap();
+    let paths: Vec<&[u8]> = set.iter().map(|e| e.path()).collect();
+    let expected: Vec<&[u8]> = vec![&[1, 1, 1], &[1, 1, 2], &[1, 2], &[2]];
+    assert_eq!(paths, expected);
+}
+
+#[test]
+fn test_time_pubparam() {
+    let pp = PubParam::default();
+    let tv = pp.time_to_path(TimeStamp((1 << CONST_D) - 1)).unwrap();
+    assert!(pp.check_path(&tv).is_ok());
+    assert!(pp.time_to_path(TimeStamp(1 << CONST_D)).is_err());
+
+    // the bounds follow the depth of the parameters
+    let pp = pp.truncate(4).unwrap();
+    assert_eq!(pp.successor_set(TimeStamp(3)).unwrap().len(), 3);
+    assert!(pp.time_to_path(TimeStamp(16)).is_err());
+    assert!(pp.check_path(&tv).is_err());
+}
@@ -0,0 +1,56 @@
+// INSECURE: this module is for tests only.
+//
+// It generates public parameters with known discrete logs,
+// `h = g^alpha` and `h_i = g^{alpha_i}` where `g = PixelG1::one()`,
+// so that pixel signature verification equations can be checked cheaply.
+// Anyone who knows the trapdoor can forge signatures.
+//
+// Such parameters carry `TRAPDOOR_CIPHERSUITE`, which is not a valid
+// ciphersuite id: they cannot be serialized, nor loaded from a blob,
+// so they never leak into deployment.
+
+use crate::constants::{CONST_D, TRAPDOOR_CIPHERSUITE};
+use crate::{GenericPubParam, PixelEngine};
+use ff::Field;
+use pairing::CurveProjective;
+use rand_core::{CryptoRng, RngCore};
+
+/// The discrete logs of `h` and `h_0, ..., h_d` w.r.t. `PixelG1::one()`.
+#[derive(Clone)]
+pub struct Trapdoor<E: PixelEngine> {
+    /// The discrete log of `h`.
+    pub alpha: <E::PixelG1 as CurveProjective>::Scalar,
+    /// The discrete logs of `h_0, ..., h_d`.
+    pub alpha_list: [<E::PixelG1 as CurveProjective>::Scalar; CONST_D + 1],
+}
+
+/// The trapdoor is not printed, to avoid leaking it into logs.
+impl<E: PixelEngine> core::fmt::Debug for Trapdoor<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Trapdoor {{ .. }}")
+    }
+}
+
+impl<E: PixelEngine> GenericPubParam<E> {
+    /// INSECURE, for tests only.
+    /// Samples the scalars `alpha, alpha_0, ..., alpha_d`, and returns the
+    /// public parameters `h = g^alpha`, `h_i = g^{alpha_i}` alongside the scalars.
+    /// The parameters carry `TRAPDOOR_CIPHERSUITE`, and cannot be serialized.
+    pub fn with_trapdoor<R: RngCore + CryptoRng>(rng: &mut R) -> (Self, Trapdoor<E>) {
+        let alpha = <E::PixelG1 as CurveProjective>::Scalar::random(rng);
+        let mut h = E::PixelG1::one();
+        h.mul_assign(alpha);
+
+        let mut alpha_list = [<E::PixelG1 as CurveProjective>::Scalar::zero(); CONST_D + 1];
+        let mut hlist = [E::PixelG1::zero(); CONST_D + 1];
+        for (a, hi) in alpha_list.iter_mut().zip(hlist.iter_mut()) {
+            *a = <E::PixelG1 as CurveProjective>::Scalar::random(rng);
+            *hi = E::PixelG1::one();
+            hi.mul_assign(*a);
+        }
+
+        let pp =
+            GenericPubParam::from_parts(CONST_D, TRAPDOOR_CIPHERSUITE, E::PixelG2::one(), h, hlist);
+        (pp, Trapdoor { alpha, alpha_list })
+    }
+}
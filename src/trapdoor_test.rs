@@ -0,0 +1,41 @@
+// This module tests the (insecure) trapdoor parameters.
+
+use crate::constants::{TRAPDOOR_CIPHERSUITE, VALID_CIPHERSUITE};
+use crate::{PixelG1, PubParam};
+use pairing::CurveProjective;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn test_trapdoor() {
+    let mut rng = StdRng::from_seed([0x42; 32]);
+    let (pp, trapdoor) = PubParam::with_trapdoor(&mut rng);
+
+    // the trapdoor gives the discrete logs of the parameters
+    let mut h = PixelG1::one();
+    h.mul_assign(trapdoor.alpha);
+    assert_eq!(pp.h(), h);
+    for (hi, ai) in pp.hlist().iter().zip(trapdoor.alpha_list.iter()) {
+        let mut expect = PixelG1::one();
+        expect.mul_assign(*ai);
+        assert_eq!(*hi, expect);
+    }
+    assert_eq!(pp.g2(), PubParam::default().g2());
+}
+
+#[test]
+fn test_trapdoor_not_serializable() {
+    let mut rng = StdRng::from_seed([0x42; 32]);
+    let (pp, _) = PubParam::with_trapdoor(&mut rng);
+
+    // the ciphersuite is not a production one
+    assert_eq!(pp.ciphersuite(), TRAPDOOR_CIPHERSUITE);
+    assert!(!VALID_CIPHERSUITE.contains(&TRAPDOOR_CIPHERSUITE));
+    assert!(pp.to_bytes(true).is_err());
+    assert!(pp.to_bytes(false).is_err());
+
+    // and the same holds for a blob claiming to be one
+    let mut bytes = PubParam::default().to_bytes(true).unwrap();
+    bytes[0] = TRAPDOOR_CIPHERSUITE;
+    assert!(PubParam::from_bytes(&bytes, true).is_err());
+}
@@ -0,0 +1,100 @@
+// This module exposes the public parameters to javascript via wasm-bindgen.
+// Group elements are returned as byte arrays, using the same
+// encoding as the parameter blob, see `serdes::BlobPart`.
+
+use crate::serdes::BlobPart;
+use crate::PubParam;
+use pairing::bls12_381::Bls12;
+use wasm_bindgen::prelude::*;
+
+/// A wrapper of `PubParam` that is exported to javascript as `PubParam`.
+#[wasm_bindgen(js_name = PubParam)]
+#[derive(Clone, Debug)]
+pub struct WasmPubParam(PubParam);
+
+#[wasm_bindgen(js_class = PubParam)]
+impl WasmPubParam {
+    /// Generate the public parameter from a seed and a ciphersuite id.
+    /// Throws if the seed is too short or the ciphersuite is not supported.
+    pub fn init(seed: &[u8], ciphersuite: u8) -> Result<WasmPubParam, JsValue> {
+        match PubParam::init(seed, ciphersuite) {
+            Ok(p) => Ok(WasmPubParam(p)),
+            Err(e) => Err(JsValue::from_str(&e)),
+        }
+    }
+
+    /// Returns the default public parameter.
+    #[wasm_bindgen(js_name = defaultParam)]
+    pub fn default_param() -> WasmPubParam {
+        WasmPubParam(PubParam::default())
+    }
+
+    /// Convert the public parameter into a blob.
+    pub fn serialize(&self, compressed: bool) -> Result<Vec<u8>, JsValue> {
+        match self.0.to_bytes(compressed) {
+            Ok(p) => Ok(p),
+            Err(e) => Err(JsValue::from_str(&e)),
+        }
+    }
+
+    /// Convert a blob into a public parameter.
+    /// Throws if the blob is malformed.
+    pub fn deserialize(bytes: &[u8], compressed: bool) -> Result<WasmPubParam, JsValue> {
+        match PubParam::from_bytes(bytes, compressed) {
+            Ok(p) => Ok(WasmPubParam(p)),
+            Err(e) => Err(JsValue::from_str(&e)),
+        }
+    }
+
+    /// Returns the 32 bytes fingerprint of the public parameter.
+    pub fn fingerprint(&self) -> Vec<u8> {
+        self.0.fingerprint().to_vec()
+    }
+
+    /// Returns the depth of the time stamp.
+    pub fn depth(&self) -> usize {
+        self.0.depth()
+    }
+
+    /// Returns the ciphersuite id.
+    pub fn ciphersuite(&self) -> u8 {
+        self.0.ciphersuite()
+    }
+
+    /// Returns the encoded `PixelG2` generator.
+    pub fn g2(&self, compressed: bool) -> Vec<u8> {
+        let mut buf = vec![];
+        BlobPart::<Bls12>::G2(self.0.g2_affine()).append_to(&mut buf, compressed);
+        buf
+    }
+
+    /// Returns the encoded `h` parameter.
+    pub fn h(&self, compressed: bool) -> Vec<u8> {
+        let mut buf = vec![];
+        BlobPart::<Bls12>::G1(self.0.h_affine()).append_to(&mut buf, compressed);
+        buf
+    }
+
+    /// Returns the encoded `h_0, h_1, ..., h_d`, concatenated.
+    /// Each element has the same length, so the array can be split
+    /// into `depth + 1` chunks.
+    pub fn hlist(&self, compressed: bool) -> Vec<u8> {
+        let mut buf = vec![];
+        for e in self.0.hlist_affine().iter() {
+            BlobPart::<Bls12>::G1(e).append_to(&mut buf, compressed);
+        }
+        buf
+    }
+}
+
+impl From<PubParam> for WasmPubParam {
+    fn from(pp: PubParam) -> Self {
+        WasmPubParam(pp)
+    }
+}
+
+impl From<WasmPubParam> for PubParam {
+    fn from(pp: WasmPubParam) -> Self {
+        pp.0
+    }
+}
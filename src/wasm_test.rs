@@ -0,0 +1,47 @@
+// This module tests the wasm bindings.
+// Run with `wasm-pack test --node -- --features wasm`.
+
+use crate::constants::{SHA512_IV, VALID_CIPHERSUITE};
+use crate::wasm::WasmPubParam;
+use crate::{PubParam, PP_LEN_COMPRESSED, PP_LEN_UNCOMPRESSED};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn test_wasm_default() {
+    let pp = WasmPubParam::default_param();
+    let pp_with_seed = WasmPubParam::init(SHA512_IV.as_ref(), VALID_CIPHERSUITE[0]).unwrap();
+    assert_eq!(pp.fingerprint(), pp_with_seed.fingerprint());
+    assert_eq!(pp.fingerprint(), PubParam::default().fingerprint().to_vec());
+    assert_eq!(pp.depth(), PubParam::default().depth());
+    assert_eq!(pp.ciphersuite(), VALID_CIPHERSUITE[0]);
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_init() {
+    // seed too short
+    assert!(WasmPubParam::init(&[0u8; 31], 0).is_err());
+    // invalid ciphersuite
+    assert!(WasmPubParam::init(SHA512_IV.as_ref(), 0xFF).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_serialization() {
+    let pp = WasmPubParam::default_param();
+    for &(compressed, len) in [(true, PP_LEN_COMPRESSED), (false, PP_LEN_UNCOMPRESSED)].iter() {
+        let blob = pp.serialize(compressed).unwrap();
+        assert_eq!(blob.len(), len);
+        let pp_recover = WasmPubParam::deserialize(&blob, compressed).unwrap();
+        assert_eq!(pp.fingerprint(), pp_recover.fingerprint());
+
+        // h is the first element after g2 and hlist follows h
+        let h = pp.h(compressed);
+        let hlist = pp.hlist(compressed);
+        assert_eq!(blob[len - hlist.len()..].to_vec(), hlist);
+        assert_eq!(
+            blob[len - hlist.len() - h.len()..len - hlist.len()].to_vec(),
+            h
+        );
+        assert_eq!(hlist.len(), (pp.depth() + 1) * h.len());
+    }
+    assert!(WasmPubParam::deserialize(&[0u8; 2], true).is_err());
+}
@@ -0,0 +1,78 @@
+// A C program that exercises the pixel_param C ABI.
+// It is compiled and run by `src/ffi_test.rs`, with the expected lengths
+// of the compressed and uncompressed blobs as arguments;
+// it returns 0 on success, and otherwise the line number of the failed check.
+
+#include <stdlib.h>
+#include <string.h>
+#include "pixel_param.h"
+
+#define CHECK(cond)           \
+    do {                      \
+        if (!(cond)) {        \
+            return __LINE__;  \
+        }                     \
+    } while (0)
+
+int main(int argc, char **argv) {
+    // the seed of the default parameter, i.e., SHA512's initial vector
+    const uint8_t sha512_iv[64] = {
+        0x6a, 0x09, 0xe6, 0x67, 0xf3, 0xbc, 0xc9, 0x08, 0xbb, 0x67, 0xae, 0x85, 0x84, 0xca, 0xa7, 0x3b,
+        0x3c, 0x6e, 0xf3, 0x72, 0xfe, 0x94, 0xf8, 0x2b, 0xa5, 0x4f, 0xf5, 0x3a, 0x5f, 0x1d, 0x36, 0xf1,
+        0x51, 0x0e, 0x52, 0x7f, 0xad, 0xe6, 0x82, 0xd1, 0x9b, 0x05, 0x68, 0x8c, 0x2b, 0x3e, 0x6c, 0x1f,
+        0x1f, 0x83, 0xd9, 0xab, 0xfb, 0x41, 0xbd, 0x6b, 0x5b, 0xe0, 0xcd, 0x19, 0x13, 0x7e, 0x21, 0x79,
+    };
+    PubParam *def = NULL;
+    PubParam *pp = NULL;
+    PubParam *recovered = NULL;
+    uint8_t fp_def[32];
+    uint8_t fp_pp[32];
+    uint8_t buf[8192];
+    uintptr_t len = 0;
+    uint8_t csid;
+
+    CHECK(argc == 3);
+
+    // the default parameter is generated from SHA512_IV
+    CHECK(pixel_param_default(&def) == PIXEL_PARAM_OK);
+    csid = pixel_param_ciphersuite(def);
+    CHECK(pixel_param_init(sha512_iv, sizeof(sha512_iv), csid, &pp) == PIXEL_PARAM_OK);
+    CHECK(pixel_param_depth(pp) == 32);
+    CHECK(pixel_param_ciphersuite(pp) == csid);
+    CHECK(pixel_param_fingerprint(def, fp_def) == PIXEL_PARAM_OK);
+    CHECK(pixel_param_fingerprint(pp, fp_pp) == PIXEL_PARAM_OK);
+    CHECK(memcmp(fp_def, fp_pp, 32) == 0);
+
+    // invalid inputs are reported with error codes
+    CHECK(pixel_param_init(sha512_iv, 31, csid, &recovered) == PIXEL_PARAM_ERR_SEED_TOO_SHORT);
+    CHECK(pixel_param_init(sha512_iv, sizeof(sha512_iv), 0xFF, &recovered) == PIXEL_PARAM_ERR_CIPHERSUITE);
+    CHECK(pixel_param_init(NULL, 0, csid, &recovered) == PIXEL_PARAM_ERR_NULL_POINTER);
+    CHECK(recovered == NULL);
+
+    // query the length of the blob, then serialize and deserialize it
+    CHECK(pixel_param_serialize(pp, true, NULL, &len) == PIXEL_PARAM_ERR_BUFFER_TOO_SMALL);
+    CHECK(len == strtoul(argv[1], NULL, 10));
+    CHECK(pixel_param_serialize(pp, true, buf, &len) == PIXEL_PARAM_OK);
+    CHECK(pixel_param_deserialize(buf, len, true, &recovered) == PIXEL_PARAM_OK);
+    CHECK(pixel_param_fingerprint(recovered, fp_pp) == PIXEL_PARAM_OK);
+    CHECK(memcmp(fp_def, fp_pp, 32) == 0);
+    pixel_param_free(recovered);
+    recovered = NULL;
+
+    len = sizeof(buf);
+    CHECK(pixel_param_serialize(pp, false, buf, &len) == PIXEL_PARAM_OK);
+    CHECK(len == strtoul(argv[2], NULL, 10));
+    CHECK(pixel_param_deserialize(buf, len, false, &recovered) == PIXEL_PARAM_OK);
+    pixel_param_free(recovered);
+    recovered = NULL;
+
+    // a corrupted blob is rejected
+    buf[0] = 0xFF;
+    CHECK(pixel_param_deserialize(buf, len, false, &recovered) == PIXEL_PARAM_ERR_DESERIALIZE);
+    CHECK(pixel_param_deserialize(buf, 10, false, &recovered) == PIXEL_PARAM_ERR_DESERIALIZE);
+
+    pixel_param_free(def);
+    pixel_param_free(pp);
+    pixel_param_free(NULL);
+    return 0;
+}